@@ -1,12 +1,35 @@
-use crate::commands::{clone, init, list};
-use clap::{Args, Parser, Subcommand};
+use crate::commands::{check, clone, config, discover, history, init, list, manage, refs, registry};
+use crate::error::ActionError;
+use crate::config::ColorMode;
+use crate::ui::{OutputFormat, Verbosity};
+use crate::utils::MirrorRule;
+use clap::{Args, Command, CommandFactory, FromArgMatches, Parser, Subcommand};
 
 #[derive(Parser)]
 #[command(name = "cvue")]
 #[command(about = "模板项目管理工具", long_about = None)]
+#[command(disable_version_flag = true)]
 pub struct Cli {
+    /// 输出格式: table 为彩色表格，json/yaml/plain 适合脚本处理
+    #[arg(short = 'o', long, global = true, value_enum, default_value_t = OutputFormat::Table)]
+    pub output: OutputFormat,
+    /// 安静模式: 只输出数据和错误
+    #[arg(short, long, global = true, conflicts_with = "verbose")]
+    pub quiet: bool,
+    /// 输出调试信息，-vv 输出更详细的跟踪信息
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    pub verbose: u8,
+    /// 颜色输出 [默认: 配置中的 ui.color]
+    #[arg(long, global = true, value_enum)]
+    pub color: Option<ColorMode>,
+    /// 界面语言: zh-CN | en [默认: 配置中的 ui.locale 或系统语言]
+    #[arg(long, global = true, value_name = "LANG")]
+    pub lang: Option<String>,
+    /// 显示版本信息
+    #[arg(short = 'V', long)]
+    pub version: bool,
     #[command(subcommand)]
-    pub command: Commands,
+    pub command: Option<Commands>,
 }
 
 #[derive(Subcommand)]
@@ -14,6 +37,8 @@ pub enum Commands {
     /// 展示所有模板(支持交互式操作) [别名: s, list]
     #[command(alias = "s", alias = "list")]
     Show(ShowArgs),
+    /// 按别名、描述和标签搜索模板
+    Search(SearchArgs),
     /// 添加模板 [别名: a]
     #[command(alias = "a")]
     Add(AddArgs),
@@ -23,6 +48,19 @@ pub enum Commands {
     /// 更新模板 [别名: u]
     #[command(alias = "u")]
     Update(UpdateArgs),
+    /// 重命名模板，指向它的替代模板引用一并修改 [别名: mv]
+    #[command(alias = "mv")]
+    Rename {
+        /// 原别名
+        old: String,
+        /// 新别名
+        new: String,
+    },
+    /// 在编辑器中以 YAML 编辑模板，保存后校验
+    Edit {
+        /// 别名
+        alias: String,
+    },
     /// 获取指定模板详情 [别名: g]
     #[command(alias = "g")]
     Get(GetArgs),
@@ -32,13 +70,198 @@ pub enum Commands {
     /// 初始化新项目 [别名: i]
     #[command(alias = "i")]
     Init(InitArgs),
+    /// 检查模板仓库是否可访问、固定的 ref 是否存在
+    Check(CheckArgs),
+    /// 列出模板仓库的标签和分支，标签按版本排序
+    Refs(RefsArgs),
+    /// 在 GitHub 上按主题搜索模板仓库并添加到模板库
+    Discover(DiscoverArgs),
+    /// 订阅共享的远程模板库
+    Registry(RegistryArgs),
+    /// 撤销最近的模板库修改
+    Undo {
+        /// 撤销的次数
+        #[arg(default_value_t = 1, value_parser = clap::value_parser!(u64).range(1..))]
+        steps: u64,
+    },
+    /// 管理cvue配置
+    Config(ConfigArgs),
+}
+
+#[derive(Args)]
+pub struct RegistryArgs {
+    #[command(subcommand)]
+    pub command: RegistryCommands,
+}
+
+#[derive(Subcommand)]
+pub enum RegistryCommands {
+    /// 订阅模板库并立即同步一次
+    Subscribe {
+        /// 模板库地址: YAML 文件的 URL 或路径，或 git 仓库
+        source: String,
+        /// 订阅名称，用作别名前缀 [默认: 由地址推导]
+        #[arg(short, long)]
+        name: Option<String>,
+        /// git 仓库中模板库文件的路径 [默认: templates.yaml]
+        #[arg(long)]
+        path: Option<String>,
+    },
+    /// 取消订阅并删除缓存 [别名: rm]
+    #[command(alias = "rm")]
+    Unsubscribe {
+        /// 订阅名称
+        name: String,
+    },
+    /// 重新拉取订阅的模板库
+    Sync {
+        /// 只同步这些订阅 [默认: 全部]
+        names: Vec<String>,
+    },
+    /// 列出订阅及同步状态 [别名: ls]
+    #[command(alias = "ls")]
+    List,
+    /// 检查模板库: 重复的别名和仓库、多个默认模板、无效的仓库地址等
+    Doctor {
+        /// 逐项修复发现的问题
+        #[arg(long)]
+        fix: bool,
+        /// 修复时不询问，使用默认的处理方式
+        #[arg(short, long, requires = "fix")]
+        yes: bool,
+    },
+    /// 查看模板库的修改历史
+    Log {
+        /// 最多显示的记录数
+        #[arg(short = 'n', long, default_value_t = 20)]
+        limit: usize,
+    },
+    /// 把本地模板库导出到标准输出
+    Export {
+        /// 文档格式
+        #[arg(long, value_enum, default_value_t = registry::DocumentFormat::Yaml)]
+        format: registry::DocumentFormat,
+        /// 按别名、描述和标签模糊筛选
+        #[arg(long)]
+        query: Option<String>,
+        /// 只导出带有该标签的模板，可多次指定
+        #[arg(long = "tag", value_name = "TAG")]
+        tags: Vec<String>,
+        /// 按字段筛选，前缀匹配，可多次指定 [例如: vue=3, build=vite]
+        #[arg(long = "where", value_name = "FIELD=VALUE")]
+        fields: Vec<list::FieldFilter>,
+    },
+    /// 从文件或标准输入导入模板，写入前预览变更
+    Import {
+        /// 文件路径，`-` 表示标准输入
+        file: String,
+        /// 文档格式 [默认: 按扩展名推断]
+        #[arg(long, value_enum)]
+        format: Option<registry::DocumentFormat>,
+        /// 别名已存在且内容不同时的处理方式
+        #[arg(long, value_enum, default_value_t = registry::ConflictStrategy::Skip)]
+        strategy: registry::ConflictStrategy,
+        /// 不询问直接导入
+        #[arg(short, long)]
+        yes: bool,
+        /// 只预览，不修改模板库
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+#[derive(Args)]
+pub struct DiscoverArgs {
+    /// 附加的搜索关键字
+    pub query: Option<String>,
+    /// 搜索的主题，可多次指定或用逗号分隔 [默认: vue-template, vite-template]
+    #[arg(short = 't', long = "topic", value_name = "TOPIC", value_delimiter = ',')]
+    pub topics: Vec<String>,
+    /// 最多显示的结果数量
+    #[arg(short = 'n', long, default_value_t = 20)]
+    pub limit: usize,
+}
+
+#[derive(Args)]
+pub struct RefsArgs {
+    /// 模板别名或仓库地址
+    pub template: String,
+    /// 只显示满足模板版本约束的标签
+    #[arg(long)]
+    pub matching: bool,
+}
+
+#[derive(Args)]
+pub struct CheckArgs {
+    /// 只检查这些模板 [默认: 全部]
+    pub aliases: Vec<String>,
+    /// 同时探测的仓库数量 [默认: 配置中的 network.jobs 或 4]
+    #[arg(short, long)]
+    pub jobs: Option<usize>,
+    /// 单个仓库的超时(秒) [默认: 配置中的 network.connect_timeout 或 30]
+    #[arg(long)]
+    pub timeout: Option<u64>,
 }
 
 #[derive(Args)]
 pub struct ShowArgs {
-    /// 是否使用交互模式
+    /// 进入交互式管理: 浏览、添加、克隆、编辑、重命名、删除模板，支持批量操作
     #[arg(short = 'i', long = "interactive", default_value_t = false, action = clap::ArgAction::SetTrue)]
     pub interactive: bool,
+    /// 按别名、描述和标签模糊筛选
+    #[arg(long)]
+    pub query: Option<String>,
+    /// 只显示带有该标签的模板，可多次指定
+    #[arg(long = "tag", value_name = "TAG")]
+    pub tags: Vec<String>,
+    /// 只显示默认模板
+    #[arg(long)]
+    pub default: bool,
+    /// 按字段筛选，前缀匹配，可多次指定 [例如: vue=3, build=vite]
+    #[arg(long = "where", value_name = "FIELD=VALUE")]
+    pub fields: Vec<list::FieldFilter>,
+    /// 按字段排序
+    #[arg(long, value_enum)]
+    pub sort: Option<list::TemplateField>,
+}
+
+/// 模板元数据参数，`add` 和 `update` 共用
+#[derive(Args)]
+pub struct MetaArgs {
+    /// Vue 版本
+    #[arg(long)]
+    pub vue: Option<String>,
+    /// UI 组件库
+    #[arg(long = "ui")]
+    pub ui_library: Option<String>,
+    /// 构建工具
+    #[arg(long = "build")]
+    pub build_tool: Option<String>,
+    /// 许可证
+    #[arg(long)]
+    pub license: Option<String>,
+    /// 维护者
+    #[arg(long)]
+    pub maintainer: Option<String>,
+}
+
+impl From<MetaArgs> for list::TemplateMeta {
+    fn from(args: MetaArgs) -> Self {
+        list::TemplateMeta {
+            vue: args.vue,
+            ui_library: args.ui_library,
+            build_tool: args.build_tool,
+            license: args.license,
+            maintainer: args.maintainer,
+        }
+    }
+}
+
+#[derive(Args)]
+pub struct SearchArgs {
+    /// 搜索关键字，多个关键字需同时匹配
+    #[arg(required = true)]
+    pub terms: Vec<String>,
 }
 
 #[derive(Args)]
@@ -52,9 +275,23 @@ pub struct AddArgs {
     /// 描述
     #[arg(short = 'e', long)]
     pub description: String,
+    /// 固定的分支或标签
+    #[arg(long = "ref", value_name = "REF")]
+    pub git_ref: Option<String>,
+    /// semver 版本约束，例如 ^2.1
+    #[arg(long, value_name = "REQ")]
+    pub version_req: Option<String>,
     /// 是否设为默认
     #[arg(short, long, default_value_t = false)]
     pub default: bool,
+    /// 标签，可多次指定或用逗号分隔
+    #[arg(short = 'g', long = "tag", value_name = "TAG", value_delimiter = ',')]
+    pub tags: Vec<String>,
+    /// 从仓库的 package.json 推断元数据，显式指定的字段优先
+    #[arg(long)]
+    pub detect: bool,
+    #[command(flatten)]
+    pub meta: MetaArgs,
 }
 
 #[derive(Args)]
@@ -74,9 +311,32 @@ pub struct UpdateArgs {
     /// 描述
     #[arg(short = 'e', long)]
     pub description: Option<String>,
+    /// 固定的分支或标签，传空字符串取消固定
+    #[arg(long = "ref", value_name = "REF")]
+    pub git_ref: Option<String>,
+    /// semver 版本约束，例如 ^2.1，传空字符串取消约束
+    #[arg(long, value_name = "REQ")]
+    pub version_req: Option<String>,
     /// 是否设为默认
     #[arg(short, long)]
     pub default: Option<bool>,
+    /// 替换标签，可多次指定或用逗号分隔
+    #[arg(short = 'g', long = "tag", value_name = "TAG", value_delimiter = ',')]
+    pub tags: Option<Vec<String>>,
+    #[command(flatten)]
+    pub meta: MetaArgs,
+    /// 标记为已弃用，false 时同时清除替代模板和原因
+    #[arg(long)]
+    pub deprecated: Option<bool>,
+    /// 替代模板的别名
+    #[arg(long, value_name = "ALIAS")]
+    pub replaced_by: Option<String>,
+    /// 弃用原因
+    #[arg(long, value_name = "REASON")]
+    pub deprecation_reason: Option<String>,
+    /// 按键名修改任意字段，可多次指定，值为空时清除 [例如: meta.vue=3, tags=[admin, ts]]
+    #[arg(long = "field", value_name = "KEY=VALUE")]
+    pub fields: Vec<list::FieldAssignment>,
 }
 
 #[derive(Args)]
@@ -84,6 +344,9 @@ pub struct GetArgs {
     /// 别名
     #[arg(short, long)]
     pub alias: String,
+    /// 只使用缓存的上游仓库信息，不访问网络
+    #[arg(long)]
+    pub offline: bool,
 }
 
 #[derive(Args)]
@@ -93,9 +356,27 @@ pub struct CloneArgs {
     /// 目标目录
     #[arg(short = 't', long)]
     pub target: Option<String>,
+    /// 包名(默认根据目标目录推导)
+    #[arg(short = 'n', long)]
+    pub name: Option<String>,
     /// Git Token
     #[arg(short = 'k', long)]
     pub token: Option<String>,
+    /// 检出的分支或标签 [默认: 模板固定的 ref 或远程默认分支]
+    #[arg(long = "ref", value_name = "REF")]
+    pub git_ref: Option<String>,
+    /// 每个源失败后的重试次数 [默认: 2]
+    #[arg(long)]
+    pub retries: Option<u32>,
+    /// 连接超时(秒) [默认: 30]
+    #[arg(long)]
+    pub connect_timeout: Option<u64>,
+    /// 空闲超时(秒)，超过该时间没有进度即视为卡死 [默认: 120]
+    #[arg(long)]
+    pub idle_timeout: Option<u64>,
+    /// 镜像改写规则，可多次指定，先于配置中的镜像尝试 [格式: FROM=TO]
+    #[arg(short = 'm', long = "mirror", value_name = "FROM=TO")]
+    pub mirrors: Vec<MirrorRule>,
 }
 
 #[derive(Args)]
@@ -103,40 +384,321 @@ pub struct InitArgs {
     /// 强制覆盖已有模板
     #[arg(short, long, default_value_t = false)]
     pub force: bool,
+    /// 移除已弃用的内置模板，默认模板迁移到替代模板
+    #[arg(long, requires = "force")]
+    pub migrate: bool,
+}
+
+#[derive(Args)]
+pub struct ConfigArgs {
+    #[command(subcommand)]
+    pub command: ConfigCommands,
+}
+
+#[derive(Subcommand)]
+pub enum ConfigCommands {
+    /// 查看配置项的生效值
+    Get {
+        /// 配置项，例如 network.proxy
+        key: String,
+    },
+    /// 设置配置项，列表类型用逗号分隔
+    Set {
+        /// 配置项，例如 network.proxy
+        key: String,
+        /// 配置值
+        value: String,
+    },
+    /// 删除配置项，恢复默认值
+    Unset {
+        /// 配置项，例如 network.proxy
+        key: String,
+    },
+    /// 列出所有配置项及其来源
+    #[command(alias = "ls")]
+    List,
+    /// 用 $EDITOR 编辑配置文件
+    Edit,
+    /// 管理地址改写规则(类似 git insteadOf)
+    #[command(name = "url-rewrite")]
+    UrlRewrite(UrlRewriteArgs),
 }
 
-pub fn run() -> Result<(), Box<dyn std::error::Error>> {
-    let cli = Cli::parse();
-    match cli.command {
+#[derive(Args)]
+pub struct UrlRewriteArgs {
+    #[command(subcommand)]
+    pub command: UrlRewriteCommands,
+}
+
+#[derive(Subcommand)]
+pub enum UrlRewriteCommands {
+    /// 添加改写规则，相同前缀会被覆盖
+    Add {
+        /// 需要改写的地址前缀
+        prefix: String,
+        /// 替换后的地址前缀
+        replacement: String,
+    },
+    /// 删除改写规则
+    #[command(alias = "rm")]
+    Remove {
+        /// 规则的地址前缀
+        prefix: String,
+    },
+    /// 列出改写规则
+    #[command(alias = "ls")]
+    List,
+    /// 显示模板或仓库改写后的最终地址
+    Test {
+        /// 模板别名或仓库地址
+        repo: String,
+    },
+}
+
+/// 用当前语言的消息目录替换命令及参数的帮助文本
+///
+/// 消息键为 `help.cvue[.子命令...][.参数]`
+fn localize(mut cmd: Command, key: &str) -> Command {
+    cmd = cmd.about(crate::i18n::t(key));
+    let args: Vec<String> = cmd.get_arguments().map(|a| a.get_id().to_string()).collect();
+    for id in args {
+        let help = crate::i18n::t(&format!("{}.{}", key, id));
+        cmd = cmd.mut_arg(id, |arg| arg.help(help));
+    }
+    let subs: Vec<String> = cmd.get_subcommands().map(|s| s.get_name().to_string()).collect();
+    for name in subs {
+        let sub_key = format!("{}.{}", key, name);
+        cmd = cmd.mut_subcommand(name, |sub| localize(sub, &sub_key));
+    }
+    cmd
+}
+
+/// 子命令的名称和别名，模板别名不能与它们相同
+pub fn reserved_names() -> Vec<String> {
+    Cli::command()
+        .get_subcommands()
+        .flat_map(|sub| std::iter::once(sub.get_name()).chain(sub.get_all_aliases()))
+        .chain(std::iter::once("help"))
+        .map(str::to_string)
+        .collect()
+}
+
+/// 本地化后的命令定义
+fn command() -> Command {
+    localize(Cli::command(), "help.cvue")
+}
+
+/// 在 clap 解析前取出 `--lang`，以便帮助和参数错误也使用该语言
+fn lang_from_args() -> Option<String> {
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--" {
+            break;
+        }
+        if arg == "--lang" {
+            return args.next();
+        }
+        if let Some(lang) = arg.strip_prefix("--lang=") {
+            return Some(lang.to_string());
+        }
+    }
+    None
+}
+
+pub fn run() -> Result<(), ActionError> {
+    // 语言取决于配置，需先加载配置再解析参数
+    let config_result = crate::config::init();
+    let lang = lang_from_args();
+    crate::i18n::init(crate::i18n::detect(&[
+        lang.as_deref(),
+        crate::config::get().ui.locale.as_deref(),
+    ]));
+    let cli = Cli::from_arg_matches(&command().get_matches()).unwrap_or_else(|e| e.exit());
+    crate::ui::set_output_format(cli.output);
+    crate::ui::set_verbosity(match (cli.quiet, cli.verbose) {
+        (true, _) => Verbosity::Quiet,
+        (false, 0) => Verbosity::Normal,
+        (false, 1) => Verbosity::Debug,
+        (false, _) => Verbosity::Trace,
+    });
+    if let Err(e) = config_result {
+        // 配置损坏时仍允许通过 config 子命令修复
+        if !matches!(cli.command, Some(Commands::Config(_))) {
+            return Err(e);
+        }
+        crate::ui::print_warning(&e.to_string());
+    }
+    crate::ui::apply_color(cli.color.unwrap_or(crate::config::get().ui.color));
+    crate::ui::debug(&crate::i18n::tr!("common.debug_config", path = crate::config::config_path().display()));
+    crate::ui::debug(&crate::i18n::tr!("common.debug_registry", path = list::templates_path()));
+
+    // 只有 `cvue` 和 `cvue --version` 显示应用标志
+    let Some(command) = cli.command else {
+        crate::ui::print_logo();
+        if !cli.version {
+            println!();
+            command().print_help()?;
+        }
+        return Ok(());
+    };
+    match command {
         Commands::Show(args) => {
+            let filter = list::TemplateFilter {
+                query: args.query,
+                tags: args.tags,
+                default_only: args.default,
+                fields: args.fields,
+                sort: args.sort,
+            };
             if args.interactive {
-                list::show_templates_interactive();
+                manage::run(filter)?;
             } else {
-                list::show_templates();
+                list::show_templates(filter)?;
             }
         }
+        Commands::Search(args) => {
+            list::search_templates(args.terms)?;
+        }
         Commands::Add(args) => {
-            list::add_template(args.alias, args.repo, args.description, args.default);
+            list::add_template(
+                list::TemplateInfo {
+                    alias: args.alias,
+                    repo: args.repo,
+                    git_ref: args.git_ref,
+                    version_req: args.version_req,
+                    description: args.description,
+                    is_default: args.default,
+                    tags: args.tags,
+                    meta: args.meta.into(),
+                    ..Default::default()
+                },
+                args.detect,
+            )?;
         }
         Commands::Remove(args) => {
-            list::remove_template(args.alias);
+            list::remove_template(args.alias)?;
         }
         Commands::Update(args) => {
-            list::update_template(args.alias, args.repo, args.description, args.default);
+            list::update_template(
+                args.alias,
+                list::TemplateUpdate {
+                    repo: args.repo,
+                    git_ref: args.git_ref,
+                    version_req: args.version_req,
+                    description: args.description,
+                    is_default: args.default,
+                    tags: args.tags,
+                    meta: args.meta.into(),
+                    deprecated: args.deprecated,
+                    replaced_by: args.replaced_by,
+                    deprecation_reason: args.deprecation_reason,
+                    fields: args.fields,
+                },
+            )?;
         }
+        Commands::Rename { old, new } => list::rename_template(old, new)?,
+        Commands::Edit { alias } => list::edit_template(alias)?,
         Commands::Get(args) => {
-            list::get_template(args.alias);
+            list::get_template(args.alias, args.offline)?;
         }
         Commands::Clone(args) => {
+            // 命令行参数优先于配置文件
+            let mut network = clone::NetworkOptions::from_config()?;
+            network.retries = args.retries.unwrap_or(network.retries);
+            network.connect_timeout = args.connect_timeout.unwrap_or(network.connect_timeout);
+            network.idle_timeout = args.idle_timeout.unwrap_or(network.idle_timeout);
+            network.mirrors.splice(0..0, args.mirrors);
             clone::run(clone::CloneArgs {
                 template: args.template,
                 target: args.target,
+                name: args.name,
                 token: args.token,
+                git_ref: args.git_ref,
+                network,
             })?;
         }
         Commands::Init(args) => {
-            init::run(init::InitArgs { force: args.force })?;
+            init::run(init::InitArgs {
+                force: args.force,
+                migrate: args.migrate,
+            })?;
         }
+        Commands::Check(args) => {
+            let network = &crate::config::get().network;
+            check::run(check::CheckArgs {
+                aliases: args.aliases,
+                jobs: args.jobs.or(network.jobs.map(|j| j as usize)).unwrap_or(4),
+                timeout: args.timeout.or(network.connect_timeout).unwrap_or(30),
+            })?;
+        }
+        Commands::Discover(args) => {
+            discover::run(discover::DiscoverArgs {
+                query: args.query,
+                topics: args.topics,
+                limit: args.limit,
+            })?;
+        }
+        Commands::Undo { steps } => history::undo(steps as usize)?,
+        Commands::Registry(args) => {
+            let action = match args.command {
+                RegistryCommands::Log { limit } => registry::RegistryAction::Log { limit },
+                RegistryCommands::Doctor { fix, yes } => {
+                    registry::RegistryAction::Doctor(crate::commands::doctor::DoctorArgs { fix, yes })
+                }
+                RegistryCommands::Subscribe { source, name, path } => {
+                    registry::RegistryAction::Subscribe { source, name, path }
+                }
+                RegistryCommands::Unsubscribe { name } => registry::RegistryAction::Unsubscribe { name },
+                RegistryCommands::Sync { names } => registry::RegistryAction::Sync { names },
+                RegistryCommands::List => registry::RegistryAction::List,
+                RegistryCommands::Export { format, query, tags, fields } => {
+                    registry::RegistryAction::Export(registry::ExportArgs {
+                        format,
+                        filter: list::TemplateFilter {
+                            query,
+                            tags,
+                            default_only: false,
+                            fields,
+                            sort: None,
+                        },
+                    })
+                }
+                RegistryCommands::Import { file, format, strategy, yes, dry_run } => {
+                    registry::RegistryAction::Import(registry::ImportArgs {
+                        file,
+                        format,
+                        strategy,
+                        yes,
+                        dry_run,
+                    })
+                }
+            };
+            registry::run(action)?;
+        }
+        Commands::Refs(args) => {
+            refs::run(refs::RefsArgs {
+                template: args.template,
+                matching: args.matching,
+            })?;
+        }
+        Commands::Config(args) => match args.command {
+            ConfigCommands::Get { key } => config::run(config::ConfigAction::Get { key })?,
+            ConfigCommands::Set { key, value } => config::run(config::ConfigAction::Set { key, value })?,
+            ConfigCommands::Unset { key } => config::run(config::ConfigAction::Unset { key })?,
+            ConfigCommands::List => config::run(config::ConfigAction::List)?,
+            ConfigCommands::Edit => config::run(config::ConfigAction::Edit)?,
+            ConfigCommands::UrlRewrite(args) => {
+                let action = match args.command {
+                    UrlRewriteCommands::Add { prefix, replacement } => {
+                        config::UrlRewriteAction::Add { prefix, replacement }
+                    }
+                    UrlRewriteCommands::Remove { prefix } => config::UrlRewriteAction::Remove { prefix },
+                    UrlRewriteCommands::List => config::UrlRewriteAction::List,
+                    UrlRewriteCommands::Test { repo } => config::UrlRewriteAction::Test { repo },
+                };
+                config::run_url_rewrite(action)?;
+            }
+        },
     }
     Ok(())
 }
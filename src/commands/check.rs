@@ -0,0 +1,423 @@
+use crate::commands::list::{load_all_templates, not_found, TemplateInfo};
+use crate::commands::refs;
+use crate::error::ActionError;
+use crate::git::RemoteRef;
+use crate::i18n::tr;
+use crate::ui::OutputFormat;
+use serde::Serialize;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// 健康检查命令参数
+pub struct CheckArgs {
+    /// 只检查这些别名，为空时检查全部模板
+    pub aliases: Vec<String>,
+    /// 同时探测的仓库数量
+    pub jobs: usize,
+    /// 单个仓库的超时(秒)
+    pub timeout: u64,
+}
+
+/// 单个模板的检查结论
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckStatus {
+    /// 仓库可访问，固定的 ref 存在
+    Ok,
+    /// 仓库地址无效
+    Invalid,
+    /// 仓库无法访问 (不存在、需要认证或超时)
+    Unreachable,
+    /// 仓库可访问但固定的 ref 不存在
+    RefMissing,
+}
+
+impl CheckStatus {
+    /// 显示名称
+    pub fn label(self) -> String {
+        match self {
+            CheckStatus::Ok => tr!("check.status_ok"),
+            CheckStatus::Invalid => tr!("check.status_invalid"),
+            CheckStatus::Unreachable => tr!("check.status_unreachable"),
+            CheckStatus::RefMissing => tr!("check.status_ref_missing"),
+        }
+    }
+
+    /// 纯文本输出使用的状态名
+    pub fn code(self) -> &'static str {
+        match self {
+            CheckStatus::Ok => "ok",
+            CheckStatus::Invalid => "invalid",
+            CheckStatus::Unreachable => "unreachable",
+            CheckStatus::RefMissing => "ref_missing",
+        }
+    }
+}
+
+/// 单个模板的检查结果
+#[derive(Debug, Clone, Serialize)]
+pub struct CheckResult {
+    pub alias: String,
+    pub repo: String,
+    #[serde(rename = "ref", skip_serializing_if = "Option::is_none")]
+    pub git_ref: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version_req: Option<String>,
+    pub status: CheckStatus,
+    /// 固定的 ref (未固定时为满足版本约束的最新标签或 HEAD) 指向的提交
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commit: Option<String>,
+    /// 失败原因
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    pub elapsed_ms: u64,
+}
+
+/// 探测设置
+#[derive(Debug, Clone)]
+pub struct ProbeOptions {
+    pub jobs: usize,
+    pub timeout: Duration,
+    pub proxy: Option<String>,
+}
+
+/// 运行健康检查命令
+pub fn run(args: CheckArgs) -> Result<(), ActionError> {
+    let templates = load_all_templates();
+    let selected: Vec<TemplateInfo> = if args.aliases.is_empty() {
+        templates.clone()
+    } else {
+        args.aliases
+            .iter()
+            .map(|alias| {
+                templates
+                    .iter()
+                    .find(|t| &t.alias == alias)
+                    .cloned()
+                    .ok_or_else(|| not_found(&templates, alias))
+            })
+            .collect::<Result<_, _>>()?
+    };
+    if selected.is_empty() {
+        crate::ui::print_warning(&tr!("common.no_templates"));
+        return Ok(());
+    }
+
+    let opts = ProbeOptions {
+        jobs: args.jobs.max(1),
+        timeout: Duration::from_secs(args.timeout),
+        proxy: crate::config::get().network.proxy.clone(),
+    };
+    let spinner = crate::ui::decorated()
+        .then(|| crate::ui::create_spinner(&tr!("check.probing", done = 0, total = selected.len())));
+    let results = check_all(&selected, &opts, |done, total| {
+        if let Some(spinner) = &spinner {
+            spinner.set_message(tr!("check.probing", done = done, total = total));
+        }
+    });
+    if let Some(spinner) = spinner {
+        spinner.finish_and_clear();
+    }
+
+    match crate::ui::output_format() {
+        OutputFormat::Table => {
+            crate::ui::print_title(&tr!("check.title"));
+            crate::ui::print_check_table(&results);
+        }
+        OutputFormat::Plain => {
+            for result in &results {
+                let detail = result
+                    .message
+                    .clone()
+                    .or_else(|| result.commit.clone())
+                    .unwrap_or_default();
+                println!("{}\t{}\t{}", result.alias, result.status.code(), detail);
+            }
+        }
+        _ => crate::ui::print_data(&results)?,
+    }
+
+    let failed = results.iter().filter(|r| r.status != CheckStatus::Ok).count();
+    if failed > 0 {
+        return Err(ActionError::CheckFailed { failed });
+    }
+    crate::ui::print_success(&tr!("check.all_ok", count = results.len()));
+    Ok(())
+}
+
+/// 并发检查多个模板，结果顺序与输入一致
+///
+/// 每完成一个模板调用一次 `progress(已完成数量, 总数)`
+pub fn check_all<F>(templates: &[TemplateInfo], opts: &ProbeOptions, progress: F) -> Vec<CheckResult>
+where
+    F: Fn(usize, usize) + Sync,
+{
+    let next = AtomicUsize::new(0);
+    let done = AtomicUsize::new(0);
+    let results = Mutex::new(vec![None; templates.len()]);
+    thread::scope(|scope| {
+        for _ in 0..opts.jobs.min(templates.len()) {
+            scope.spawn(|| loop {
+                let index = next.fetch_add(1, Ordering::SeqCst);
+                let Some(tpl) = templates.get(index) else {
+                    break;
+                };
+                let result = check_template(tpl, opts);
+                if let Ok(mut results) = results.lock() {
+                    results[index] = Some(result);
+                }
+                progress(done.fetch_add(1, Ordering::SeqCst) + 1, templates.len());
+            });
+        }
+    });
+    results
+        .into_inner()
+        .unwrap_or_default()
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+/// 检查单个模板: 校验地址，用 `git ls-remote` 探测可达性并解析固定的 ref
+pub fn check_template(tpl: &TemplateInfo, opts: &ProbeOptions) -> CheckResult {
+    let started = Instant::now();
+    let (status, commit, message) = match probe(tpl, opts) {
+        // 未固定 ref 但有版本约束时，检查是否有满足约束的标签
+        Ok(remote) => match (&tpl.git_ref, &tpl.version_req) {
+            (None, Some(req)) => match refs::parse_req(req) {
+                Ok(parsed) => match refs::latest_matching(&refs::classify(&remote, Some(&parsed)), &parsed) {
+                    Some(found) => (CheckStatus::Ok, Some(found.commit.clone()), None),
+                    None => (
+                        CheckStatus::RefMissing,
+                        None,
+                        Some(tr!("check.no_matching_tag", req = req)),
+                    ),
+                },
+                Err(e) => (CheckStatus::Invalid, None, Some(e.to_string())),
+            },
+            (git_ref, _) => {
+                let wanted = git_ref.as_deref().unwrap_or("HEAD");
+                match resolve_ref(&remote, wanted) {
+                    Some(sha) => (CheckStatus::Ok, Some(sha), None),
+                    // ls-remote 只列出分支和标签指向的提交，其它提交无法在不克隆的情况下确认
+                    None if crate::git::looks_like_commit(wanted) => (
+                        CheckStatus::Ok,
+                        None,
+                        Some(tr!("check.commit_unverified", git_ref = wanted)),
+                    ),
+                    None => (
+                        CheckStatus::RefMissing,
+                        None,
+                        Some(tr!("check.ref_missing", git_ref = wanted)),
+                    ),
+                }
+            }
+        },
+        Err(e @ ActionError::InvalidRepo(_)) => (CheckStatus::Invalid, None, Some(e.to_string())),
+        Err(e) => (CheckStatus::Unreachable, None, Some(e.to_string())),
+    };
+    CheckResult {
+        alias: tpl.alias.clone(),
+        repo: tpl.repo.clone(),
+        git_ref: tpl.git_ref.clone(),
+        version_req: tpl.version_req.clone(),
+        status,
+        commit,
+        message,
+        elapsed_ms: started.elapsed().as_millis() as u64,
+    }
+}
+
+/// 列出模板仓库的远程引用，地址改写和认证与克隆时一致
+fn probe(tpl: &TemplateInfo, opts: &ProbeOptions) -> Result<Vec<RemoteRef>, ActionError> {
+    let url = crate::commands::clone::remote_url(&tpl.repo, None)?;
+    crate::git::ls_remote(&url, opts.timeout, opts.proxy.as_deref())
+}
+
+/// 在远程引用中查找分支、标签或提交，返回对应的提交 SHA
+///
+/// 与 `git clone --branch` 一样分支优先于同名标签，附注标签取 `^{}` 指向的提交；
+/// 看起来像提交 SHA 时与克隆一样直接匹配引用指向的提交
+pub fn resolve_ref(refs: &[RemoteRef], wanted: &str) -> Option<String> {
+    let find = |name: &str| refs.iter().find(|r| r.name == name).map(|r| r.sha.clone());
+    if crate::git::looks_like_commit(wanted) {
+        let prefix = wanted.to_lowercase();
+        if let Some(found) = refs.iter().find(|r| r.sha.starts_with(&prefix)) {
+            return Some(found.sha.clone());
+        }
+    }
+    if wanted == "HEAD" {
+        return find("HEAD");
+    }
+    find(&format!("refs/heads/{}", wanted))
+        .or_else(|| find(&format!("refs/tags/{}^{{}}", wanted)))
+        .or_else(|| find(&format!("refs/tags/{}", wanted)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::{Path, PathBuf};
+    use std::process::Command;
+
+    fn git(dir: &Path, args: &[&str]) {
+        let status = Command::new("git")
+            .args(["-c", "user.name=cvue", "-c", "user.email=cvue@example.com"])
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .unwrap()
+            .status;
+        assert!(status.success(), "git {:?} 执行失败", args);
+    }
+
+    /// 创建带有一个提交和 `v1` 标签的本地裸仓库
+    fn bare_repo(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("cvue-check-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        let work = root.join("work");
+        std::fs::create_dir_all(&work).unwrap();
+        git(&work, &["init", "-q", "-b", "main"]);
+        std::fs::write(work.join("package.json"), "{}").unwrap();
+        git(&work, &["add", "."]);
+        git(&work, &["commit", "-q", "-m", "init"]);
+        git(&work, &["tag", "-a", "v1", "-m", "v1"]);
+        git(&root, &["clone", "-q", "--bare", "work", "repo.git"]);
+        root
+    }
+
+    fn template(repo: &str, git_ref: Option<&str>) -> TemplateInfo {
+        TemplateInfo {
+            repo: repo.to_string(),
+            git_ref: git_ref.map(str::to_string),
+            ..crate::commands::list::test_template("t")
+        }
+    }
+
+    fn opts() -> ProbeOptions {
+        ProbeOptions {
+            jobs: 2,
+            timeout: Duration::from_secs(30),
+            proxy: None,
+        }
+    }
+
+    #[test]
+    fn reports_status_for_each_template() {
+        let root = bare_repo("status");
+        let repo = root.join("repo.git").display().to_string();
+        let missing = format!("file://{}", root.join("missing.git").display());
+        let templates = vec![
+            template(&repo, None),
+            template(&repo, Some("v1")),
+            template(&repo, Some("main")),
+            template(&repo, Some("v2")),
+            template(&missing, None),
+            template("not a repo", None),
+        ];
+        let results = check_all(&templates, &opts(), |_, _| {});
+        let statuses: Vec<_> = results.iter().map(|r| r.status).collect();
+        assert_eq!(
+            statuses,
+            [
+                CheckStatus::Ok,
+                CheckStatus::Ok,
+                CheckStatus::Ok,
+                CheckStatus::RefMissing,
+                CheckStatus::Unreachable,
+                CheckStatus::Invalid,
+            ]
+        );
+        // 附注标签解析到提交本身
+        assert_eq!(results[0].commit, results[1].commit);
+        let _ = std::fs::remove_dir_all(root);
+    }
+
+    #[test]
+    fn checks_version_constraints_without_pinned_ref() {
+        let root = bare_repo("constraint");
+        let repo = root.join("repo.git").display().to_string();
+        let constrained = |req: &str| TemplateInfo {
+            version_req: Some(req.to_string()),
+            ..template(&repo, None)
+        };
+        let templates = vec![constrained("^1"), constrained("^2"), constrained("not a range")];
+        let results = check_all(&templates, &opts(), |_, _| {});
+        let statuses: Vec<_> = results.iter().map(|r| r.status).collect();
+        assert_eq!(statuses, [CheckStatus::Ok, CheckStatus::RefMissing, CheckStatus::Invalid]);
+        assert!(results[0].commit.is_some() && results[1].message.is_some());
+        assert!(check_all(&[], &opts(), |_, _| unreachable!()).is_empty());
+        let _ = std::fs::remove_dir_all(root);
+    }
+
+    #[test]
+    fn clones_and_checks_pinned_commits() {
+        let root = bare_repo("commit");
+        let work = root.join("work");
+        let head = |dir: &Path| {
+            let output = Command::new("git").args(["rev-parse", "HEAD"]).current_dir(dir).output().unwrap();
+            String::from_utf8_lossy(&output.stdout).trim().to_string()
+        };
+        git(&work, &["commit", "-q", "--allow-empty", "-m", "second"]);
+        let pinned = head(&work);
+        git(&work, &["commit", "-q", "--allow-empty", "-m", "third"]);
+
+        // 不是分支或标签指向的提交，check 无法确认但不报缺失
+        let result = check_template(&template(&work.display().to_string(), Some(&pinned[..10])), &opts());
+        assert_eq!(result.status, CheckStatus::Ok);
+        assert!(result.commit.is_none() && result.message.is_some());
+
+        let opts = crate::git::CloneOptions {
+            connect_timeout: Duration::from_secs(30),
+            idle_timeout: Duration::from_secs(30),
+            proxy: None,
+            branch: Some(pinned[..10].to_string()),
+        };
+        let target = root.join("out");
+        crate::git::clone(&work.display().to_string(), &target, &opts).unwrap();
+        assert_eq!(head(&target), pinned);
+        let _ = std::fs::remove_dir_all(root);
+    }
+
+    #[test]
+    fn resolves_commit_prefix() {
+        let refs = vec![RemoteRef {
+            sha: "0123456789abcdef".to_string(),
+            name: "HEAD".to_string(),
+            ..Default::default()
+        }];
+        assert_eq!(resolve_ref(&refs, "0123456").as_deref(), Some("0123456789abcdef"));
+        assert_eq!(resolve_ref(&refs, "0123"), None);
+    }
+
+    #[test]
+    fn resolves_branches_before_peeled_tags() {
+        let remote = |name: &str, sha: &str| RemoteRef {
+            sha: sha.to_string(),
+            name: name.to_string(),
+            ..Default::default()
+        };
+        let refs = vec![
+            remote("HEAD", "aaa0000"),
+            remote("refs/heads/v1", "bbb0000"),
+            remote("refs/tags/v1", "c0ffee0"),
+            remote("refs/tags/v1^{}", "ddd0000"),
+            remote("refs/heads/main", "aaa0000"),
+            remote("refs/tags/v2", "eee0000"),
+            remote("refs/tags/v3", "fff0000"),
+            remote("refs/tags/v3^{}", "ddd0000"),
+        ];
+        // 与 git clone --branch 一样同名分支优先，附注标签取指向的提交
+        assert_eq!(resolve_ref(&refs, "v1").as_deref(), Some("bbb0000"));
+        assert_eq!(resolve_ref(&refs, "v3").as_deref(), Some("ddd0000"));
+        assert_eq!(resolve_ref(&refs, "v2").as_deref(), Some("eee0000"));
+        assert_eq!(resolve_ref(&refs, "main").as_deref(), Some("aaa0000"));
+        assert_eq!(resolve_ref(&refs, "HEAD").as_deref(), Some("aaa0000"));
+        // clone --branch 只接受短名称
+        assert_eq!(resolve_ref(&refs, "refs/heads/v1"), None);
+        assert_eq!(resolve_ref(&refs, "C0FFEE0").as_deref(), Some("c0ffee0"));
+        assert_eq!(resolve_ref(&refs, "dev"), None);
+    }
+}
@@ -1,193 +1,421 @@
-use crate::commands::list::{load_templates, get_default_template, find_by_alias};
+use crate::commands::list::{load_all_templates, get_default_template, find_by_alias, group_by_tag, resolve_alias, TemplateInfo};
 use crate::utils::*;
 use crate::error::ActionError;
+use crate::i18n::tr;
+use crate::ui::OutputFormat;
 use colored::*;
-use std::path::PathBuf;
-use dialoguer::{theme::ColorfulTheme, Select, Input};
+use dialoguer::{theme::ColorfulTheme, Confirm, Select, Input};
+use serde::Serialize;
+use std::path::Path;
+use std::time::Duration;
+use url::Url;
 
 /// 克隆命令参数结构体
 pub struct CloneArgs {
     pub template: Option<String>, // 别名或repo
     pub target: Option<String>,
+    pub name: Option<String>, // package.json 中的包名
     pub token: Option<String>,
+    /// 检出的分支或标签，优先于模板中固定的 ref
+    pub git_ref: Option<String>,
+    pub network: NetworkOptions,
+}
+
+/// 克隆结果摘要，用于结构化输出
+#[derive(Debug, Serialize)]
+pub struct CloneSummary {
+    /// 使用的模板别名，直接使用仓库地址时为空
+    pub template: Option<String>,
+    pub repo: String,
+    /// 检出的分支或标签，为空时为远程默认分支
+    #[serde(rename = "ref", skip_serializing_if = "Option::is_none")]
+    pub git_ref: Option<String>,
+    /// 实际使用的克隆源
+    pub source: String,
+    pub target: String,
+    pub package_name: String,
+    /// 是否已写入 package.json
+    pub package_name_applied: bool,
+}
+
+/// 网络相关参数：超时、重试和镜像
+#[derive(Debug, Clone)]
+pub struct NetworkOptions {
+    /// 每个源失败后的重试次数
+    pub retries: u32,
+    /// 连接超时(秒)
+    pub connect_timeout: u64,
+    /// 空闲超时(秒)
+    pub idle_timeout: u64,
+    /// 按顺序尝试的镜像规则
+    pub mirrors: Vec<MirrorRule>,
+    /// git 使用的 HTTP(S) 代理
+    pub proxy: Option<String>,
+}
+
+impl NetworkOptions {
+    /// 以默认值为基础应用配置文件中的网络设置
+    pub fn from_config() -> Result<Self, ActionError> {
+        let cfg = &crate::config::get().network;
+        let defaults = NetworkOptions::default();
+        Ok(NetworkOptions {
+            retries: cfg.retries.unwrap_or(defaults.retries),
+            connect_timeout: cfg.connect_timeout.unwrap_or(defaults.connect_timeout),
+            idle_timeout: cfg.idle_timeout.unwrap_or(defaults.idle_timeout),
+            mirrors: cfg
+                .mirrors
+                .iter()
+                .map(|m| m.parse::<MirrorRule>().map_err(ActionError::Other))
+                .collect::<Result<_, _>>()?,
+            proxy: cfg.proxy.clone(),
+        })
+    }
+}
+
+impl Default for NetworkOptions {
+    fn default() -> Self {
+        NetworkOptions {
+            retries: 2,
+            connect_timeout: 30,
+            idle_timeout: 120,
+            mirrors: Vec::new(),
+            proxy: None,
+        }
+    }
 }
 
 /// 运行克隆命令
 pub fn run(args: CloneArgs) -> Result<(), ActionError> {
-    crate::ui::print_title("克隆Vue模板");
+    crate::ui::print_title(&tr!("clone.title"));
     
     // 如果未提供模板，进入交互式模式
     if args.template.is_none() {
         return run_interactive(&args);
     }
     
-    let spinner = crate::ui::create_spinner("加载模板配置...");
-    let templates = load_templates();
-    std::thread::sleep(std::time::Duration::from_millis(500));
-    spinner.finish_and_clear();
+    let templates = load_all_templates();
 
     // 选择模板仓库
-    let repo = match &args.template {
+    let (alias, repo, pinned_ref, version_req) = match &args.template {
+        // 不是已有别名但形如仓库地址时直接克隆该仓库
+        Some(name) if find_by_alias(&templates, name).is_none() && looks_like_repo(name) => {
+            crate::ui::print_info(&tr!("clone.alias_not_found", name = mask_credentials(name)));
+            (None, name.clone(), None, None)
+        }
         Some(name) => {
-            let template_spinner = crate::ui::create_spinner(&format!("查找模板 '{}'...", name));
-            std::thread::sleep(std::time::Duration::from_millis(500));
-            
-            let result = find_by_alias(&templates, name)
-                .map(|tpl| {
-                    template_spinner.finish_and_clear();
-                    crate::ui::print_success(&format!("使用模板: {} ({})", tpl.alias, tpl.description));
-                    tpl.repo.as_str()
-                })
-                .unwrap_or_else(|| {
-                    template_spinner.finish_and_clear();
-                    crate::ui::print_info(&format!("未找到模板 '{}', 将直接使用作为仓库地址", name));
-                    name
-                })
-                .to_string();
-            
-            result
+            let tpl = check_deprecated(&templates, resolve_alias(&templates, name)?)?;
+            crate::ui::print_success(&tr!("clone.using_template", alias = tpl.alias, description = tpl.description));
+            (Some(tpl.alias.clone()), tpl.repo.clone(), tpl.git_ref.clone(), tpl.version_req.clone())
         }
-        None => {
-            let default_spinner = crate::ui::create_spinner("查找默认模板...");
-            std::thread::sleep(std::time::Duration::from_millis(500));
-            
-            match get_default_template(&templates) {
-                Some(tpl) => {
-                    default_spinner.finish_and_clear();
-                    crate::ui::print_success(&format!("使用默认模板: {} ({})", tpl.alias, tpl.description));
-                    tpl.repo.clone()
-                }
-                None => {
-                    default_spinner.finish_and_clear();
-                    return Err(ActionError::Other("没有默认模板，请先使用 'cvue init' 初始化模板或指定要使用的模板".into()));
-                }
+        None => match get_default_template(&templates) {
+            Some(tpl) => {
+                let tpl = check_deprecated(&templates, tpl)?;
+                crate::ui::print_success(&tr!("clone.using_default", alias = tpl.alias, description = tpl.description));
+                (Some(tpl.alias.clone()), tpl.repo.clone(), tpl.git_ref.clone(), tpl.version_req.clone())
             }
-        }
+            None => {
+                return Err(ActionError::Other(tr!("clone.no_default")));
+            }
+        },
     };
 
     // 设置目标目录名
     let dir_name = args
         .target
-        .as_deref()
-        .unwrap_or_else(|| repo.split('/').last().unwrap_or("template"));
+        .clone()
+        .unwrap_or_else(|| default_target(&repo));
     
-    let check_spinner = crate::ui::create_spinner(&format!("检查项目名称 '{}'...", dir_name));
-    check_name(dir_name)?;
-    let target_path = PathBuf::from(dir_name);
+    let check_spinner = crate::ui::create_spinner(&tr!("clone.checking_name", name = dir_name));
+    let target_path = resolve_target(&dir_name)?;
+    let package_name = match &args.name {
+        Some(name) => {
+            validate_package_name(name)?;
+            name.clone()
+        }
+        None => package_name_from_path(&target_path),
+    };
     check_spinner.finish_and_clear();
     
     // 目标目录存在时交互询问
     if target_path.exists() {
-        let confirm = crate::ui::confirm(&format!("目录 '{}' 已存在。是否覆盖?", target_path.display()))?;
+        let confirm = crate::ui::confirm(&tr!("clone.confirm_overwrite", path = target_path.display()))?;
         if !confirm {
-            crate::ui::print_info("已取消操作");
+            crate::ui::print_info(&tr!("common.cancelled"));
             return Ok(());
         }
         
-        let remove_spinner = crate::ui::create_spinner(&format!("正在删除目录 '{}'...", target_path.display()));
+        let remove_spinner = crate::ui::create_spinner(&tr!("clone.removing_dir", path = target_path.display()));
         std::fs::remove_dir_all(&target_path)?;
         remove_spinner.finish_and_clear();
     }
 
-    // 处理仓库地址
-    let url_spinner = crate::ui::create_spinner("准备仓库地址...");
+    // 处理仓库地址：原始地址在前，镜像按规则顺序在后
     let url = normalize_gh_url(&repo)?;
-    let url = add_gh_auth(&url, args.token.as_deref())?;
-    url_spinner.finish_and_clear();
+    let rewritten = crate::config::get().rewrite_url(&url);
+    if rewritten != url {
+        crate::ui::print_info(&tr!(
+            "clone.url_rewritten",
+            from = mask_credentials(&url),
+            to = mask_credentials(&rewritten)
+        ));
+    }
+    let url = rewritten;
+    let token = args.token.clone().or_else(|| default_token(&url));
+    // 展示地址隐藏用户写在地址中的认证信息
+    let mut sources = vec![(mask_credentials(&url), add_gh_auth(&url, token.as_deref())?)];
+    for rule in &args.network.mirrors {
+        if let Some(mirror) = rule.apply(&url) {
+            // 镜像可能是第三方代理，不携带 Token
+            sources.push((mask_credentials(&mirror), mirror));
+        }
+    }
     
-    crate::ui::print_info(&format!("将从 {} 克隆到 {}", 
-        url.replace(args.token.as_deref().unwrap_or(""), "****"), 
-        target_path.display()));
+    crate::ui::print_info(&tr!("clone.cloning_from", url = mask_credentials(&url), path = target_path.display()));
 
-    // 执行 git clone
-    let pb = crate::ui::create_spinner(&format!("正在克隆到 {}...", target_path.display()));
-    
-    let status = std::process::Command::new("git")
-        .arg("clone")
-        .arg(&url)
-        .arg(&target_path)
-        .status()
-        .map_err(|e| ActionError::Other(format!("无法执行 git: {}", e)))?;
-    
-    if status.success() {
-        pb.finish_and_clear();
-        crate::ui::print_title("克隆成功");
-        println!("{} 项目已克隆到: {}", "✓".bright_green(), target_path.display().to_string().bright_green());
-        println!("\n{} {}", "▶".bright_green(), "开始使用:".bright_green());
-        println!("  cd {}", dir_name);
-        println!("  npm install");
-        println!("  npm run dev");
-        Ok(())
-    } else {
-        pb.finish_and_clear();
-        Err(ActionError::Other(format!("git clone 失败，返回码: {:?}", status.code())))
+    // 创建缺失的上级目录
+    ensure_parent_dir(&target_path)?;
+
+    // 执行 git clone；没有指定 ref 时按版本约束选择最新的标签
+    let mut git_ref = args.git_ref.clone().or(pinned_ref);
+    if let (None, Some(req)) = (&git_ref, &version_req) {
+        let tag = crate::commands::refs::resolve_constraint(&sources, req, &args.network)?;
+        crate::ui::print_info(&tr!("clone.constraint_resolved", req = req, git_ref = tag));
+        git_ref = Some(tag);
+    }
+    let source = clone_with_retry(&sources, &target_path, &args.network, git_ref.as_deref())?;
+    crate::ui::print_success(&tr!("clone.source_used", source = source));
+
+    let package_name_applied = set_package_name(&target_path, &package_name)?;
+    if package_name_applied {
+        crate::ui::print_info(&tr!("clone.package_name_set", name = package_name));
+    }
+
+    let summary = CloneSummary {
+        template: alias,
+        repo: mask_credentials(&repo),
+        git_ref,
+        source,
+        target: target_path.display().to_string(),
+        package_name,
+        package_name_applied,
+    };
+    match crate::ui::output_format() {
+        OutputFormat::Table if crate::ui::decorated() => {}
+        // 安静模式与 plain 一样只输出目标目录
+        OutputFormat::Table | OutputFormat::Plain => {
+            println!("{}", summary.target);
+            return Ok(());
+        }
+        _ => return crate::ui::print_data(&summary),
     }
+
+    crate::ui::print_title(&tr!("clone.success_title"));
+    println!(
+        "{} {}",
+        "✓".bright_green(),
+        tr!("clone.cloned_to", path = target_path.display()).bright_green()
+    );
+    println!("\n{} {}", "▶".bright_green(), tr!("clone.get_started").bright_green());
+    let pm = crate::config::get().clone.package_manager;
+    println!("  cd {}", target_path.display());
+    println!("  {}", pm.install_cmd());
+    println!("  {}", pm.dev_cmd());
+    Ok(())
+}
+
+/// 模板已弃用时给出警告，并在终端中询问是否改用替代模板
+fn check_deprecated<'a>(
+    templates: &'a [TemplateInfo],
+    tpl: &'a TemplateInfo,
+) -> Result<&'a TemplateInfo, ActionError> {
+    if !tpl.deprecated {
+        return Ok(tpl);
+    }
+    crate::ui::print_warning(&tr!("clone.deprecated", alias = tpl.alias));
+    if let Some(reason) = &tpl.deprecation_reason {
+        crate::ui::print_warning(&tr!("clone.deprecation_reason", reason = reason));
+    }
+    let Some(replacement) = tpl
+        .replaced_by
+        .as_deref()
+        .and_then(|alias| find_by_alias(templates, alias))
+    else {
+        return Ok(tpl);
+    };
+    // 非交互环境不替用户做决定，只给出提示
+    if !crate::ui::interactive() {
+        crate::ui::print_info(&tr!("clone.replacement_hint", replacement = replacement.alias));
+        return Ok(tpl);
+    }
+    let use_replacement = Confirm::with_theme(&ColorfulTheme::default())
+        .with_prompt(tr!(
+            "clone.use_replacement",
+            replacement = replacement.alias,
+            description = replacement.description
+        ))
+        .default(true)
+        .interact()
+        .map_err(|e| ActionError::Other(tr!("common.interaction_error", error = e)))?;
+    Ok(if use_replacement { replacement } else { tpl })
+}
+
+/// 未显式指定 Token 时使用的 Token: 仅对 GitHub 地址读取环境变量
+pub fn default_token(url: &str) -> Option<String> {
+    Url::parse(url)
+        .ok()
+        .filter(|u| u.host_str() == Some("github.com"))
+        .and_then(|_| crate::config::get().env_token())
+}
+
+/// 访问仓库使用的地址: 标准化、按配置改写并附加 Token
+///
+/// 未指定 Token 时使用 [`default_token`]
+pub fn remote_url(repo: &str, token: Option<&str>) -> Result<String, ActionError> {
+    let url = crate::config::get().rewrite_url(&normalize_gh_url(repo)?);
+    let token = token.map(str::to_string).or_else(|| default_token(&url));
+    add_gh_auth(&url, token.as_deref())
+}
+
+/// 未指定目标目录时的默认目标：配置的上级目录 + 根据仓库推导的目录名
+pub fn default_target(repo: &str) -> String {
+    let dir_name = default_dir_name(repo);
+    match &crate::config::get().clone.target_dir {
+        Some(parent) => Path::new(parent).join(dir_name).to_string_lossy().into_owned(),
+        None => dir_name,
+    }
+}
+
+/// 依次尝试各个克隆源，每个源按指数退避重试
+///
+/// `sources` 为 (展示地址, 实际地址) 列表，成功时返回所用源的展示地址
+fn clone_with_retry(
+    sources: &[(String, String)],
+    target: &Path,
+    network: &NetworkOptions,
+    git_ref: Option<&str>,
+) -> Result<String, ActionError> {
+    let opts = crate::git::CloneOptions {
+        connect_timeout: Duration::from_secs(network.connect_timeout),
+        idle_timeout: Duration::from_secs(network.idle_timeout),
+        proxy: network.proxy.clone(),
+        branch: git_ref.map(str::to_string),
+    };
+    let mut last_err = None;
+    for (display, url) in sources {
+        crate::ui::print_info(&tr!("clone.trying_source", source = display));
+        for attempt in 0..=network.retries {
+            if attempt > 0 {
+                let delay = backoff_delay(attempt);
+                crate::ui::print_warning(&tr!(
+                    "clone.retry_in",
+                    secs = delay.as_secs(),
+                    attempt = attempt
+                ));
+                std::thread::sleep(delay);
+            }
+            match crate::git::clone(url, target, &opts) {
+                Ok(()) => return Ok(display.clone()),
+                Err(e) => {
+                    crate::ui::print_warning(&tr!("clone.failed", error = e));
+                    // 清理失败留下的半成品目录
+                    if target.exists() {
+                        std::fs::remove_dir_all(target)?;
+                    }
+                    last_err = Some(e);
+                }
+            }
+        }
+    }
+    match last_err {
+        Some(e) => {
+            crate::ui::print_error(&tr!("clone.all_sources_failed"));
+            Err(e)
+        }
+        None => Err(ActionError::Other(tr!("clone.no_sources"))),
+    }
+}
+
+/// 第 n 次重试前的等待时间：1, 2, 4 ... 秒，最多 30 秒
+fn backoff_delay(attempt: u32) -> Duration {
+    Duration::from_secs((1u64 << (attempt - 1).min(5)).min(30))
 }
 
 /// 运行交互式模板选择
 fn run_interactive(args: &CloneArgs) -> Result<(), ActionError> {
-    crate::ui::print_title("交互式模板选择");
+    crate::ui::print_title(&tr!("clone.interactive_title"));
 
-    let templates = load_templates();
+    let templates = group_by_tag(load_all_templates());
     if templates.is_empty() {
-        crate::ui::print_warning("没有可用的模板，请先运行 'cvue init' 初始化模板库。");
+        crate::ui::print_warning(&tr!("clone.no_templates"));
         return Ok(());
     }
 
     // 准备模板列表
-    let template_options: Vec<String> = templates
-        .iter()
-        .map(|t| {
-            format!(
-                "{} - {} {}",
-                t.alias.bright_cyan(),
-                t.description,
-                if t.is_default {
-                    "[默认]".bright_green()
-                } else {
-                    "".into()
-                }
-            )
-        })
-        .collect();
+    let template_options: Vec<String> = templates.iter().map(crate::ui::template_option).collect();
 
     // 添加一个选项用于输入自定义仓库
     let mut display_options = template_options.clone();
-    display_options.push("输入自定义仓库地址...".bright_yellow().to_string());
+    display_options.push(tr!("clone.custom_repo").bright_yellow().to_string());
     
     // 选择模板
-    println!("请选择要克隆的模板：");
     let theme = ColorfulTheme::default();
     let selection = Select::with_theme(&theme)
+        .with_prompt(tr!("clone.select_template"))
         .items(&display_options)
         .default(0)
         .interact()
-        .map_err(|e| ActionError::Other(format!("交互错误: {}", e)))?;
+        .map_err(|e| ActionError::Other(tr!("common.interaction_error", error = e)))?;
 
-    let template = if selection == templates.len() {
+    let (template, repo, pinned_ref, version_req) = if selection == templates.len() {
         // 用户选择了自定义仓库
         let repo_url: String = Input::with_theme(&theme)
-            .with_prompt("请输入GitHub仓库地址 (user/repo 或 URL)")
+            .with_prompt(tr!("clone.prompt_repo"))
             .interact_text()
-            .map_err(|e| ActionError::Other(format!("交互错误: {}", e)))?;
+            .map_err(|e| ActionError::Other(tr!("common.interaction_error", error = e)))?;
         
-        Some(repo_url)
+        (Some(repo_url.clone()), repo_url, None, None)
     } else {
         // 用户选择了预设模板
-        Some(templates[selection].alias.clone())
+        let tpl = &templates[selection];
+        (Some(tpl.alias.clone()), tpl.repo.clone(), tpl.git_ref.clone(), tpl.version_req.clone())
+    };
+
+    // 选择版本：命令行或模板已固定 ref 时不再询问
+    let git_ref = match args.git_ref.clone().or(pinned_ref) {
+        Some(git_ref) => Some(git_ref),
+        None => crate::commands::refs::pick_ref(&repo, version_req.as_deref(), args.token.as_deref())?,
     };
 
-    // 输入目标目录
+    // 输入目标目录，默认根据仓库推导
     let target: String = Input::with_theme(&theme)
-        .with_prompt("请输入目标目录名称")
+        .with_prompt(tr!("clone.prompt_target"))
+        .with_initial_text(default_target(&repo))
+        .interact_text()
+        .map_err(|e| ActionError::Other(tr!("common.interaction_error", error = e)))?;
+
+    // 输入包名，默认根据目标目录推导
+    let suggested = resolve_target(&target)
+        .map(|path| package_name_from_path(&path))
+        .unwrap_or_else(|_| suggest_package_name(&target));
+    let name: String = Input::with_theme(&theme)
+        .with_prompt(tr!("clone.prompt_package_name"))
+        .default(suggested)
+        .validate_with(|input: &String| -> Result<(), String> {
+            validate_package_name(input).map_err(|e| {
+                tr!("clone.package_name_hint", error = e, suggestion = suggest_package_name(input))
+            })
+        })
         .interact_text()
-        .map_err(|e| ActionError::Other(format!("交互错误: {}", e)))?;
+        .map_err(|e| ActionError::Other(tr!("common.interaction_error", error = e)))?;
 
     // 准备克隆参数
     let clone_args = CloneArgs {
         template,
         target: Some(target),
+        name: Some(name),
         token: args.token.clone(),
+        git_ref,
+        network: args.network.clone(),
     };
 
     // 执行克隆
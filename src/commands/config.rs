@@ -0,0 +1,171 @@
+use crate::commands::list::{find_by_alias, load_all_templates};
+use crate::config::{self, KeyKind, KEYS};
+use crate::error::ActionError;
+use crate::i18n::tr;
+use crate::utils::normalize_gh_url;
+use colored::*;
+use toml::Value;
+
+/// 配置子命令
+pub enum ConfigAction {
+    Get { key: String },
+    Set { key: String, value: String },
+    Unset { key: String },
+    List,
+    Edit,
+}
+
+/// 地址改写规则子命令
+pub enum UrlRewriteAction {
+    Add { prefix: String, replacement: String },
+    Remove { prefix: String },
+    List,
+    Test { repo: String },
+}
+
+/// 运行配置子命令
+pub fn run(action: ConfigAction) -> Result<(), ActionError> {
+    match action {
+        ConfigAction::Get { key } => {
+            config::key_kind(&key)?;
+            match config::get().effective_value(&key) {
+                Some(value) => println!("{}", config::display_value(&value)),
+                None => crate::ui::print_info(&tr!("config.unset_key", key = key)),
+            }
+        }
+        ConfigAction::Set { key, value } => {
+            let kind = config::key_kind(&key)?;
+            let mut table = config::load_table()?;
+            config::set_value(&mut table, &key, config::parse_value(kind, &value)?);
+            config::save_table(&table)?;
+            crate::ui::print_success(&tr!("config.set_done", key = key, value = value));
+        }
+        ConfigAction::Unset { key } => {
+            config::key_kind(&key)?;
+            let mut table = config::load_table()?;
+            if !config::unset_value(&mut table, &key) {
+                return Err(ActionError::Other(tr!("config.unset_key", key = key)));
+            }
+            config::save_table(&table)?;
+            crate::ui::print_success(&tr!("config.unset_done", key = key));
+        }
+        ConfigAction::List => list(),
+        ConfigAction::Edit => edit()?,
+    }
+    Ok(())
+}
+
+/// 列出所有配置项的生效值及来源
+fn list() {
+    crate::ui::print_title(&tr!("config.title"));
+    crate::ui::print_info(&tr!("config.file", path = config::config_path().display()));
+    let file = config::load_table().unwrap_or_default();
+    let effective = config::get();
+    for (key, kind) in KEYS {
+        let value = effective
+            .effective_value(key)
+            .map(|v| config::display_value(&v))
+            .unwrap_or_default();
+        let source = if std::env::var(config::env_name(key)).is_ok() {
+            config::env_name(key)
+        } else if config::get_value(&file, key).is_some() {
+            tr!("config.source_file")
+        } else {
+            tr!("config.source_default")
+        };
+        let value = if value.is_empty() && *kind != KeyKind::List {
+            "-".dimmed().to_string()
+        } else {
+            value.bright_green().to_string()
+        };
+        println!("  {} = {}  {}", key.bright_cyan(), value, format!("[{}] {}", source, config::key_help(key)).dimmed());
+    }
+    if !effective.url_rewrite.is_empty() {
+        println!();
+        crate::ui::print_info(&tr!("config.see_url_rewrite"));
+    }
+}
+
+/// 用 $VISUAL / $EDITOR 打开配置文件，保存后校验
+fn edit() -> Result<(), ActionError> {
+    let path = config::config_path();
+    if !path.exists() {
+        config::save_table(&config::load_table()?)?;
+    }
+    crate::utils::open_in_editor(&path)?;
+    // 校验编辑后的内容
+    config::save_table(&config::load_table()?)?;
+    crate::ui::print_success(&tr!("config.saved"));
+    Ok(())
+}
+
+/// 运行地址改写规则子命令
+pub fn run_url_rewrite(action: UrlRewriteAction) -> Result<(), ActionError> {
+    match action {
+        UrlRewriteAction::Add { prefix, replacement } => {
+            let mut table = config::load_table()?;
+            let rules = url_rewrite_rules(&mut table);
+            // 相同前缀的规则直接覆盖
+            rules.retain(|r| r.get("prefix").and_then(Value::as_str) != Some(prefix.as_str()));
+            let mut rule = toml::Table::new();
+            rule.insert("prefix".into(), Value::String(prefix.clone()));
+            rule.insert("replacement".into(), Value::String(replacement.clone()));
+            rules.push(Value::Table(rule));
+            config::save_table(&table)?;
+            crate::ui::print_success(&tr!("config.rule_added", prefix = prefix, replacement = replacement));
+        }
+        UrlRewriteAction::Remove { prefix } => {
+            let mut table = config::load_table()?;
+            let rules = url_rewrite_rules(&mut table);
+            let old_len = rules.len();
+            rules.retain(|r| r.get("prefix").and_then(Value::as_str) != Some(prefix.as_str()));
+            if rules.len() == old_len {
+                return Err(ActionError::Other(tr!("config.rule_not_found", prefix = prefix)));
+            }
+            if rules.is_empty() {
+                table.remove("url_rewrite");
+            }
+            config::save_table(&table)?;
+            crate::ui::print_success(&tr!("config.rule_removed", prefix = prefix));
+        }
+        UrlRewriteAction::List => {
+            crate::ui::print_title(&tr!("config.rules_title"));
+            let rules = &config::get().url_rewrite;
+            if rules.is_empty() {
+                crate::ui::print_info(&tr!("config.no_rules"));
+            }
+            for rule in rules {
+                println!("  {} → {}", rule.prefix.bright_cyan(), rule.replacement.bright_green());
+            }
+        }
+        UrlRewriteAction::Test { repo } => {
+            // 支持直接传入模板别名
+            let templates = load_all_templates();
+            let repo = find_by_alias(&templates, &repo)
+                .map(|tpl| tpl.repo.clone())
+                .unwrap_or(repo);
+            let url = normalize_gh_url(&repo)?;
+            let rewritten = config::get().rewrite_url(&url);
+            crate::ui::print_detail_card(&[
+                (tr!("config.original_url"), url.clone()),
+                (tr!("config.final_url"), rewritten.bright_green().to_string()),
+                (
+                    tr!("config.rewritten"),
+                    if rewritten != url { tr!("common.yes") } else { tr!("common.no") },
+                ),
+            ]);
+        }
+    }
+    Ok(())
+}
+
+/// 取得配置表中的改写规则数组，不存在时创建
+fn url_rewrite_rules(table: &mut toml::Table) -> &mut Vec<Value> {
+    let entry = table
+        .entry("url_rewrite")
+        .or_insert_with(|| Value::Array(Vec::new()));
+    if !entry.is_array() {
+        *entry = Value::Array(Vec::new());
+    }
+    entry.as_array_mut().expect("url_rewrite 已确保为数组")
+}
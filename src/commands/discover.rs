@@ -0,0 +1,219 @@
+use crate::commands::list::{find_by_alias, load_all_templates, load_templates_for_update, normalize_tags, save_templates, TemplateInfo, TemplateMeta};
+use crate::error::ActionError;
+use crate::github::SearchHit;
+use crate::i18n::tr;
+use crate::ui::OutputFormat;
+use dialoguer::{theme::ColorfulTheme, MultiSelect};
+
+/// 未指定 `--topic` 时搜索的主题
+pub const DEFAULT_TOPICS: &[&str] = &["vue-template", "vite-template"];
+
+/// 发现模板命令参数
+pub struct DiscoverArgs {
+    /// 附加的搜索关键字
+    pub query: Option<String>,
+    /// 搜索的主题，为空时使用 [`DEFAULT_TOPICS`]
+    pub topics: Vec<String>,
+    /// 最多显示的结果数量
+    pub limit: usize,
+}
+
+/// 运行发现模板命令
+///
+/// 终端中用多选列表选择要添加的仓库，否则只输出搜索结果
+pub fn run(args: DiscoverArgs) -> Result<(), ActionError> {
+    let topics = if args.topics.is_empty() {
+        DEFAULT_TOPICS.iter().map(|t| t.to_string()).collect()
+    } else {
+        args.topics
+    };
+    let spinner = crate::ui::decorated().then(|| crate::ui::create_spinner(&tr!("discover.searching")));
+    let result = crate::github::search_repositories(&crate::github::api_url(), args.query.as_deref(), &topics, args.limit);
+    if let Some(spinner) = spinner {
+        spinner.finish_and_clear();
+    }
+
+    // 已在模板库 (包括订阅的模板库) 中的仓库不再列出
+    let all = load_all_templates();
+    let (known, hits): (Vec<SearchHit>, Vec<SearchHit>) = result?.into_iter().partition(|hit| {
+        all.iter().any(|t| {
+            crate::github::repo_path(&t.repo).is_some_and(|p| p.eq_ignore_ascii_case(&hit.full_name))
+        })
+    });
+    if !known.is_empty() {
+        crate::ui::print_info(&tr!("discover.skipped_known", count = known.len()));
+    }
+
+    match crate::ui::output_format() {
+        OutputFormat::Table if hits.is_empty() => {
+            crate::ui::print_warning(&tr!("discover.no_results"));
+            Ok(())
+        }
+        OutputFormat::Table if crate::ui::interactive() => pick_and_add(load_templates_for_update()?, &hits, &topics),
+        OutputFormat::Table => {
+            crate::ui::print_title(&tr!("discover.title"));
+            crate::ui::print_discover_table(&hits);
+            Ok(())
+        }
+        OutputFormat::Plain => {
+            for hit in &hits {
+                println!("{}\t{}\t{}", hit.full_name, hit.stars, hit.description.as_deref().unwrap_or_default());
+            }
+            Ok(())
+        }
+        _ => crate::ui::print_data(&hits),
+    }
+}
+
+/// 多选要添加的仓库，生成别名后保存到模板库
+fn pick_and_add(mut templates: Vec<TemplateInfo>, hits: &[SearchHit], topics: &[String]) -> Result<(), ActionError> {
+    let options: Vec<String> = hits.iter().map(crate::ui::discover_option).collect();
+    let selected = MultiSelect::with_theme(&ColorfulTheme::default())
+        .with_prompt(tr!("discover.select"))
+        .items(&options)
+        .max_length(15)
+        .interact()
+        .map_err(|e| ActionError::Other(tr!("common.interaction_error", error = e)))?;
+    if selected.is_empty() {
+        crate::ui::print_info(&tr!("common.cancelled"));
+        return Ok(());
+    }
+
+    let mut added = Vec::new();
+    for index in selected {
+        let template = template_from_hit(&hits[index], &templates, topics);
+        added.push((template.alias.clone(), template.repo.clone()));
+        templates.push(template);
+    }
+    let spinner = crate::ui::create_spinner(&tr!("list.saving"));
+    let result = save_templates(&templates);
+    spinner.finish_and_clear();
+    result?;
+    for (alias, repo) in added {
+        crate::ui::print_success(&tr!("discover.added", alias = alias, repo = repo));
+    }
+    Ok(())
+}
+
+/// 把搜索结果转换为模板，搜索用的主题不作为标签
+pub fn template_from_hit(hit: &SearchHit, templates: &[TemplateInfo], topics: &[String]) -> TemplateInfo {
+    let (owner, name) = hit.full_name.split_once('/').unwrap_or(("", &hit.full_name));
+    TemplateInfo {
+        alias: generate_alias(owner, name, templates),
+        repo: hit.full_name.clone(),
+        description: hit.description.clone().unwrap_or_else(|| hit.full_name.clone()),
+        tags: normalize_tags(hit.topics.iter().filter(|t| !topics.contains(t)).cloned().collect()),
+        meta: TemplateMeta {
+            license: hit.license.clone(),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+/// 根据仓库名生成不重复的别名
+///
+/// 依次尝试 `repo`、`owner-repo`，仍然重复时追加数字后缀；
+/// 与子命令重名时和 `cvue doctor` 一样追加 `-template`
+pub fn generate_alias(owner: &str, name: &str, templates: &[TemplateInfo]) -> String {
+    let reserved = crate::cli::reserved_names();
+    let avoid_reserved = |alias: String| {
+        if reserved.contains(&alias) {
+            format!("{}-template", alias)
+        } else {
+            alias
+        }
+    };
+    let base = avoid_reserved(crate::utils::suggest_package_name(name));
+    let qualified = avoid_reserved(crate::utils::suggest_package_name(&format!("{}-{}", owner, name)));
+    std::iter::once(base.clone())
+        .chain(std::iter::once(qualified.clone()))
+        .chain((2..).map(|n| format!("{}-{}", qualified, n)))
+        .find(|alias| find_by_alias(templates, alias).is_none() && !reserved.contains(alias))
+        .unwrap_or(base)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+
+    #[test]
+    fn generates_unique_aliases() {
+        let existing = |aliases: &[&str]| -> Vec<TemplateInfo> {
+            aliases.iter().copied().map(crate::commands::list::test_template).collect()
+        };
+        assert_eq!(generate_alias("vuejs", "CreateVue", &[]), "create-vue");
+        assert_eq!(generate_alias("vuejs", "create-vue", &existing(&["create-vue"])), "vuejs-create-vue");
+        assert_eq!(
+            generate_alias("vuejs", "create-vue", &existing(&["create-vue", "vuejs-create-vue"])),
+            "vuejs-create-vue-2"
+        );
+        // 与子命令重名的仓库名
+        assert_eq!(generate_alias("team", "config", &[]), "config-template");
+        assert_eq!(generate_alias("team", "List", &existing(&["list-template"])), "team-list");
+        for name in ["check", "refs", "help"] {
+            let alias = generate_alias("team", name, &[]);
+            assert!(crate::commands::list::check_new_alias(&[], &alias).is_ok(), "{}", alias);
+        }
+    }
+
+    /// 在本地启动模拟的搜索 API，按请求顺序返回给定的响应体
+    fn mock_api(bodies: Vec<&'static str>) -> (String, std::thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let api = format!("http://{}", listener.local_addr().unwrap());
+        let handle = std::thread::spawn(move || {
+            let mut requests = Vec::new();
+            for body in bodies {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut request_line = String::new();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                reader.read_line(&mut request_line).unwrap();
+                // 读完请求头
+                let mut line = String::new();
+                while reader.read_line(&mut line).unwrap() > 2 {
+                    line.clear();
+                }
+                requests.push(request_line);
+                write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+            requests
+        });
+        (api, handle)
+    }
+
+    #[test]
+    fn searches_each_topic_and_merges_results() {
+        let (api, server) = mock_api(vec![
+            r#"{"items": [
+                {"full_name": "a/admin", "description": "Admin", "stargazers_count": 10, "topics": ["vue-template", "admin"]},
+                {"full_name": "b/starter", "description": " ", "stargazers_count": 50, "topics": []}
+            ]}"#,
+            r#"{"items": [
+                {"full_name": "b/starter", "stargazers_count": 50},
+                {"full_name": "c/vite", "stargazers_count": 30, "license": {"spdx_id": "MIT"}}
+            ]}"#,
+        ]);
+        let topics = vec!["vue-template".to_string(), "vite-template".to_string()];
+        let hits = crate::github::search_repositories(&api, Some("admin"), &topics, 20).unwrap();
+        let requests = server.join().unwrap();
+
+        let names: Vec<_> = hits.iter().map(|h| h.full_name.as_str()).collect();
+        assert_eq!(names, ["b/starter", "c/vite", "a/admin"]);
+        assert_eq!(hits[0].description, None);
+        assert!(requests[0].starts_with("GET /search/repositories?q=admin+topic%3Avue-template&"));
+
+        let template = template_from_hit(&hits[2], &[], &topics);
+        assert_eq!(template.alias, "admin");
+        assert_eq!(template.repo, "a/admin");
+        assert_eq!(template.tags, ["admin"]);
+        assert_eq!(template_from_hit(&hits[1], &[], &topics).meta.license.as_deref(), Some("MIT"));
+    }
+}
@@ -0,0 +1,502 @@
+//! 模板库约束的检查和修复
+//!
+//! 约束: 别名唯一、非空、不含 `/` 且不与子命令重名；最多一个默认模板；仓库地址有效；
+//! 同一仓库 (以及相同的 ref 和版本约束) 只出现一次
+
+use crate::commands::list::{read_templates, save_templates, TemplateInfo};
+use crate::error::ActionError;
+use crate::i18n::tr;
+use crate::ui::OutputFormat;
+use dialoguer::{theme::ColorfulTheme, Input, Select};
+use serde::Serialize;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// 本次运行是否已经提示过模板库的问题
+static WARNED: AtomicBool = AtomicBool::new(false);
+
+/// 检查模板库命令参数
+pub struct DoctorArgs {
+    /// 逐项修复发现的问题
+    pub fix: bool,
+    /// 修复时不询问，使用默认的处理方式
+    pub yes: bool,
+}
+
+/// 违反模板库约束的问题
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Issue {
+    /// 别名为空或包含 `/`
+    InvalidAlias { alias: String },
+    DuplicateAlias { alias: String, count: usize },
+    /// 别名与子命令或其别名相同
+    ReservedAlias { alias: String },
+    MultipleDefaults { aliases: Vec<String> },
+    InvalidRepo { alias: String, repo: String },
+    DuplicateRepo { repo: String, aliases: Vec<String> },
+}
+
+impl Issue {
+    /// 问题描述
+    pub fn message(&self) -> String {
+        match self {
+            Issue::InvalidAlias { alias } => tr!("doctor.invalid_alias", alias = alias),
+            Issue::DuplicateAlias { alias, count } => tr!("doctor.duplicate_alias", alias = alias, count = count),
+            Issue::ReservedAlias { alias } => tr!("doctor.reserved_alias", alias = alias),
+            Issue::MultipleDefaults { aliases } => tr!("doctor.multiple_defaults", aliases = aliases.join(", ")),
+            Issue::InvalidRepo { alias, repo } => tr!("doctor.invalid_repo", alias = alias, repo = repo),
+            Issue::DuplicateRepo { repo, aliases } => {
+                tr!("doctor.duplicate_repo", repo = repo, aliases = aliases.join(", "))
+            }
+        }
+    }
+}
+
+/// 运行检查模板库命令，发现问题且未修复时返回错误
+pub fn run(args: DoctorArgs) -> Result<(), ActionError> {
+    // 由本命令自己报告问题
+    WARNED.store(true, Ordering::Relaxed);
+    let templates = read_templates()?;
+    let reserved = crate::cli::reserved_names();
+    let issues = validate_templates(&templates, &reserved);
+
+    match crate::ui::output_format() {
+        OutputFormat::Table if issues.is_empty() => crate::ui::print_success(&tr!("doctor.healthy")),
+        OutputFormat::Table => {
+            crate::ui::print_title(&tr!("doctor.title"));
+            for issue in &issues {
+                crate::ui::print_warning(&issue.message());
+            }
+        }
+        OutputFormat::Plain => {
+            for issue in &issues {
+                println!("{}", issue.message());
+            }
+        }
+        _ => crate::ui::print_data(&issues)?,
+    }
+    if issues.is_empty() {
+        return Ok(());
+    }
+    if !args.fix {
+        return Err(ActionError::Other(tr!("doctor.found", count = issues.len())));
+    }
+    if !args.yes && !crate::ui::interactive() {
+        return Err(ActionError::Other(tr!("doctor.needs_yes")));
+    }
+
+    let fixed = fix_all(templates, &reserved, args.yes)?;
+    let remaining = validate_templates(&fixed, &reserved);
+    let spinner = crate::ui::create_spinner(&tr!("list.saving"));
+    let result = save_templates(&fixed);
+    spinner.finish_and_clear();
+    result?;
+    if remaining.is_empty() {
+        crate::ui::print_success(&tr!("doctor.fixed", count = issues.len()));
+        Ok(())
+    } else {
+        for issue in &remaining {
+            crate::ui::print_warning(&issue.message());
+        }
+        Err(ActionError::Other(tr!("doctor.found", count = remaining.len())))
+    }
+}
+
+/// 检查模板库约束，`reserved` 为不能用作别名的子命令名称
+pub fn validate_templates(templates: &[TemplateInfo], reserved: &[String]) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    let mut seen: Vec<&str> = Vec::new();
+    for tpl in templates {
+        let alias = tpl.alias.as_str();
+        if alias.trim().is_empty() || alias.contains('/') {
+            issues.push(Issue::InvalidAlias { alias: alias.to_string() });
+        } else if reserved.iter().any(|r| r == alias) {
+            issues.push(Issue::ReservedAlias { alias: alias.to_string() });
+        }
+        if !seen.contains(&alias) {
+            let count = templates.iter().filter(|t| t.alias == alias).count();
+            if count > 1 {
+                issues.push(Issue::DuplicateAlias { alias: alias.to_string(), count });
+            }
+            seen.push(alias);
+        }
+        if crate::utils::normalize_gh_url(&tpl.repo).is_err() {
+            issues.push(Issue::InvalidRepo {
+                alias: alias.to_string(),
+                repo: tpl.repo.clone(),
+            });
+        }
+    }
+
+    let defaults: Vec<String> = templates.iter().filter(|t| t.is_default).map(|t| t.alias.clone()).collect();
+    if defaults.len() > 1 {
+        issues.push(Issue::MultipleDefaults { aliases: defaults });
+    }
+    for group in duplicate_repos(templates) {
+        issues.push(Issue::DuplicateRepo {
+            repo: templates[group[0]].repo.clone(),
+            aliases: group.iter().map(|&i| templates[i].alias.clone()).collect(),
+        });
+    }
+    issues
+}
+
+/// 指向同一仓库、ref 和版本约束的模板下标，每组至少两个
+fn duplicate_repos(templates: &[TemplateInfo]) -> Vec<Vec<usize>> {
+    let key = |tpl: &TemplateInfo| {
+        crate::utils::normalize_gh_url(&tpl.repo).ok().map(|url| {
+            let url = url.trim_end_matches('/').trim_end_matches(".git").to_lowercase();
+            (url, tpl.git_ref.clone(), tpl.version_req.clone())
+        })
+    };
+    let keys: Vec<_> = templates.iter().map(key).collect();
+    let mut groups: Vec<Vec<usize>> = Vec::new();
+    for (i, k) in keys.iter().enumerate() {
+        let Some(k) = k else { continue };
+        if keys[..i].iter().any(|prev| prev.as_ref() == Some(k)) {
+            continue;
+        }
+        let group: Vec<usize> = (i..keys.len()).filter(|&j| keys[j].as_ref() == Some(k)).collect();
+        if group.len() > 1 {
+            groups.push(group);
+        }
+    }
+    groups
+}
+
+/// 加载模板库时提示违反约束的问题
+pub fn warn_once(templates: &[TemplateInfo]) {
+    if WARNED.swap(true, Ordering::Relaxed) {
+        return;
+    }
+    let issues = validate_templates(templates, &crate::cli::reserved_names());
+    for issue in &issues {
+        crate::ui::print_warning(&issue.message());
+    }
+    if !issues.is_empty() {
+        crate::ui::print_info(&tr!("doctor.hint"));
+    }
+}
+
+/// 模板库文件无法解析时提示，只读命令按空列表继续
+pub fn warn_unreadable(error: &ActionError) {
+    if !WARNED.swap(true, Ordering::Relaxed) {
+        crate::ui::print_warning(&tr!("doctor.unreadable", error = error));
+    }
+}
+
+/// 依次修复别名、仓库地址、重复仓库和默认模板
+///
+/// `yes` 为真时不询问，使用每一步的默认处理方式
+pub fn fix_all(templates: Vec<TemplateInfo>, reserved: &[String], yes: bool) -> Result<Vec<TemplateInfo>, ActionError> {
+    let fixer = Fixer { reserved, yes };
+    let templates = fixer.fix_aliases(templates)?;
+    let templates = fixer.fix_repos(templates)?;
+    let templates = fixer.fix_duplicate_repos(templates)?;
+    fixer.fix_defaults(templates)
+}
+
+/// 交互式修复，`yes` 为真时直接采用默认选项
+struct Fixer<'a> {
+    reserved: &'a [String],
+    yes: bool,
+}
+
+impl Fixer<'_> {
+    /// 单选，返回选中的下标
+    fn choose(&self, prompt: &str, options: &[String], default: usize) -> Result<usize, ActionError> {
+        if self.yes {
+            return Ok(default);
+        }
+        Select::with_theme(&ColorfulTheme::default())
+            .with_prompt(prompt)
+            .items(options)
+            .default(default)
+            .interact()
+            .map_err(|e| ActionError::Other(tr!("common.interaction_error", error = e)))
+    }
+
+    /// 输入新别名，不能与 `taken` 中的别名重复
+    fn new_alias(&self, prompt: &str, suggested: String, taken: &[String]) -> Result<String, ActionError> {
+        if self.yes {
+            return Ok(suggested);
+        }
+        let reserved = self.reserved;
+        Input::with_theme(&ColorfulTheme::default())
+            .with_prompt(prompt)
+            .default(suggested)
+            .validate_with(|input: &String| -> Result<(), String> {
+                if input.trim().is_empty() || input.contains('/') {
+                    Err(tr!("doctor.invalid_alias", alias = input))
+                } else if reserved.contains(input) {
+                    Err(tr!("doctor.reserved_alias", alias = input))
+                } else if taken.contains(input) {
+                    Err(tr!("error.alias_exists", alias = input))
+                } else {
+                    Ok(())
+                }
+            })
+            .interact_text()
+            .map_err(|e| ActionError::Other(tr!("common.interaction_error", error = e)))
+    }
+
+    /// 修复无效、保留和重复的别名。重复的别名保留第一个，其余的重命名或删除
+    fn fix_aliases(&self, templates: Vec<TemplateInfo>) -> Result<Vec<TemplateInfo>, ActionError> {
+        let mut taken: Vec<String> = templates.iter().map(|t| t.alias.clone()).collect();
+        let mut kept: Vec<TemplateInfo> = Vec::new();
+        let mut renamed: Vec<(String, String)> = Vec::new();
+        for mut tpl in templates {
+            let invalid = tpl.alias.trim().is_empty() || tpl.alias.contains('/') || self.reserved.contains(&tpl.alias);
+            let original = kept.iter().find(|k| k.alias == tpl.alias);
+            let duplicate = original.is_some();
+            if !invalid && !duplicate {
+                kept.push(tpl);
+                continue;
+            }
+            if let Some(original) = original {
+                // 完全相同的重复项默认删除，否则默认重命名
+                let identical = *original == tpl;
+                let options = [tr!("doctor.option_rename"), tr!("doctor.option_remove")];
+                let prompt = tr!("doctor.duplicate_alias", alias = tpl.alias, count = 2);
+                if self.choose(&prompt, &options, usize::from(identical))? == 1 {
+                    continue;
+                }
+            }
+            let suggested = unique_alias(&suggest_alias(&tpl), self.reserved, &taken);
+            let alias = self.new_alias(&tr!("doctor.prompt_alias", alias = tpl.alias, repo = tpl.repo), suggested, &taken)?;
+            taken.push(alias.clone());
+            // 重复项改名后，原有的引用仍指向保留的那个
+            if !duplicate {
+                renamed.push((tpl.alias.clone(), alias.clone()));
+            }
+            tpl.alias = alias;
+            kept.push(tpl);
+        }
+        for tpl in kept.iter_mut() {
+            if let Some((_, new)) = renamed.iter().find(|(old, _)| tpl.replaced_by.as_ref() == Some(old)) {
+                tpl.replaced_by = Some(new.clone());
+            }
+        }
+        Ok(kept)
+    }
+
+    /// 修复无效的仓库地址: 修改、删除或保持不变
+    fn fix_repos(&self, templates: Vec<TemplateInfo>) -> Result<Vec<TemplateInfo>, ActionError> {
+        let mut kept = Vec::new();
+        for mut tpl in templates {
+            if crate::utils::normalize_gh_url(&tpl.repo).is_ok() {
+                kept.push(tpl);
+                continue;
+            }
+            let suggestion = suggest_repo(&tpl.repo);
+            let options = [
+                tr!("doctor.option_fix_repo"),
+                tr!("doctor.option_remove"),
+                tr!("doctor.option_keep"),
+            ];
+            let prompt = tr!("doctor.invalid_repo", alias = tpl.alias, repo = tpl.repo);
+            let default = if suggestion.is_some() { 0 } else { 2 };
+            match self.choose(&prompt, &options, default)? {
+                0 if self.yes => tpl.repo = suggestion.unwrap_or(tpl.repo),
+                0 => {
+                    tpl.repo = Input::with_theme(&ColorfulTheme::default())
+                        .with_prompt(tr!("list.prompt_repo"))
+                        .with_initial_text(suggestion.unwrap_or_else(|| tpl.repo.clone()))
+                        .validate_with(|input: &String| {
+                            crate::utils::normalize_gh_url(input).map(|_| ()).map_err(|e| e.to_string())
+                        })
+                        .interact_text()
+                        .map_err(|e| ActionError::Other(tr!("common.interaction_error", error = e)))?;
+                }
+                1 => continue,
+                _ => {}
+            }
+            kept.push(tpl);
+        }
+        Ok(kept)
+    }
+
+    /// 同一仓库只保留一个模板，被删除模板的引用和默认状态转移到保留的模板
+    fn fix_duplicate_repos(&self, mut templates: Vec<TemplateInfo>) -> Result<Vec<TemplateInfo>, ActionError> {
+        let mut removed: Vec<(String, String)> = Vec::new();
+        for group in duplicate_repos(&templates) {
+            let mut options = vec![tr!("doctor.option_keep_all")];
+            options.extend(group.iter().map(|&i| tr!("doctor.option_keep_only", alias = templates[i].alias)));
+            let prompt = tr!(
+                "doctor.duplicate_repo",
+                repo = templates[group[0]].repo,
+                aliases = group.iter().map(|&i| templates[i].alias.as_str()).collect::<Vec<_>>().join(", ")
+            );
+            let choice = self.choose(&prompt, &options, 1)?;
+            let Some(&keep) = choice.checked_sub(1).and_then(|c| group.get(c)) else {
+                continue;
+            };
+            let keep_alias = templates[keep].alias.clone();
+            for &i in group.iter().filter(|&&i| i != keep) {
+                if templates[i].is_default {
+                    templates[keep].is_default = true;
+                }
+                removed.push((templates[i].alias.clone(), keep_alias.clone()));
+            }
+        }
+        templates.retain(|t| !removed.iter().any(|(alias, _)| *alias == t.alias));
+        for tpl in templates.iter_mut() {
+            if let Some((_, keep)) = removed.iter().find(|(old, _)| tpl.replaced_by.as_ref() == Some(old)) {
+                tpl.replaced_by = Some(keep.clone()).filter(|keep| *keep != tpl.alias);
+            }
+        }
+        Ok(templates)
+    }
+
+    /// 多个默认模板时只保留一个，默认保留第一个
+    fn fix_defaults(&self, mut templates: Vec<TemplateInfo>) -> Result<Vec<TemplateInfo>, ActionError> {
+        let defaults: Vec<usize> = (0..templates.len()).filter(|&i| templates[i].is_default).collect();
+        if defaults.len() < 2 {
+            return Ok(templates);
+        }
+        let options: Vec<String> = defaults.iter().map(|&i| templates[i].alias.clone()).collect();
+        let choice = self.choose(&tr!("doctor.prompt_default"), &options, 0)?;
+        for (n, &i) in defaults.iter().enumerate() {
+            templates[i].is_default = n == choice;
+        }
+        Ok(templates)
+    }
+}
+
+/// 根据原别名或仓库名建议新别名
+fn suggest_alias(tpl: &TemplateInfo) -> String {
+    let base = tpl.alias.trim().replace('/', "-");
+    if base.is_empty() {
+        let name = tpl.repo.trim_end_matches('/').trim_end_matches(".git").rsplit('/').next().unwrap_or_default();
+        crate::utils::suggest_package_name(if name.is_empty() { "template" } else { name })
+    } else {
+        base
+    }
+}
+
+/// 在建议的别名后追加后缀，直到不与保留名称和已有别名重复
+///
+/// 与子命令重名时追加 `-template`，与已有别名重复时追加数字
+fn unique_alias(base: &str, reserved: &[String], taken: &[String]) -> String {
+    let base = if reserved.iter().any(|r| r == base) {
+        format!("{}-template", base)
+    } else {
+        base.to_string()
+    };
+    let free = |alias: &String| !reserved.contains(alias) && !taken.contains(alias);
+    std::iter::once(base.clone())
+        .chain((2..).map(|n| format!("{}-{}", base, n)))
+        .find(free)
+        .unwrap_or(base)
+}
+
+/// 尝试把常见的错误写法修正为 `owner/repo`，例如 `github.com/owner/repo`、`git@github.com:owner/repo.git`
+fn suggest_repo(repo: &str) -> Option<String> {
+    let trimmed = repo.trim().trim_end_matches('/');
+    let path = trimmed
+        .strip_prefix("git@github.com:")
+        .or_else(|| trimmed.strip_prefix("github.com/"))
+        .or_else(|| trimmed.strip_prefix("www.github.com/"))
+        .unwrap_or(trimmed)
+        .trim_end_matches(".git");
+    Some(path.to_string()).filter(|p| p != repo && crate::utils::normalize_gh_url(p).is_ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::list::test_template;
+
+    fn template(alias: &str, repo: &str, is_default: bool) -> TemplateInfo {
+        TemplateInfo {
+            repo: repo.into(),
+            is_default,
+            ..test_template(alias)
+        }
+    }
+
+    fn broken() -> Vec<TemplateInfo> {
+        vec![
+            template("admin", "team/admin", true),
+            template("admin", "team/admin-next", false),
+            template("list", "team/list", true),
+            template("starter", "git@github.com:team/starter.git", false),
+            template("starter-copy", "https://github.com/team/admin.git", false),
+        ]
+    }
+
+    #[test]
+    fn reports_each_invariant() {
+        let reserved = vec!["list".to_string(), "rm".to_string()];
+        let issues = validate_templates(&broken(), &reserved);
+        assert_eq!(
+            issues,
+            [
+                Issue::DuplicateAlias { alias: "admin".into(), count: 2 },
+                Issue::ReservedAlias { alias: "list".into() },
+                Issue::InvalidRepo {
+                    alias: "starter".into(),
+                    repo: "git@github.com:team/starter.git".into()
+                },
+                Issue::MultipleDefaults { aliases: vec!["admin".into(), "list".into()] },
+                Issue::DuplicateRepo {
+                    repo: "team/admin".into(),
+                    aliases: vec!["admin".into(), "starter-copy".into()]
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn fixes_with_default_choices() {
+        let reserved = vec!["list".to_string()];
+        let fixed = fix_all(broken(), &reserved, true).unwrap();
+        assert!(validate_templates(&fixed, &reserved).is_empty());
+        let aliases: Vec<_> = fixed.iter().map(|t| t.alias.as_str()).collect();
+        assert_eq!(aliases, ["admin", "admin-2", "list-template", "starter"]);
+        assert_eq!(fixed[3].repo, "team/starter");
+        assert!(fixed[0].is_default && !fixed[2].is_default);
+    }
+
+    #[test]
+    fn groups_repos_by_normalized_url_and_ref() {
+        let mut pinned = template("pinned", "team/admin", false);
+        pinned.git_ref = Some("v2".into());
+        let templates = vec![
+            template("a", "team/Admin", false),
+            template("b", "https://github.com/team/admin.git", false),
+            pinned,
+            template("", "team/blank", false),
+            template("team/x", "team/x", false),
+        ];
+        let issues = validate_templates(&templates, &[]);
+        assert_eq!(
+            issues,
+            [
+                Issue::InvalidAlias { alias: "".into() },
+                Issue::InvalidAlias { alias: "team/x".into() },
+                Issue::DuplicateRepo {
+                    repo: "team/Admin".into(),
+                    aliases: vec!["a".into(), "b".into()]
+                },
+            ]
+        );
+        assert!(validate_templates(&[], &[]).is_empty());
+    }
+
+    #[test]
+    fn suggests_aliases_and_repos() {
+        assert_eq!(suggest_alias(&template(" team/x ", "team/x", false)), "team-x");
+        assert_eq!(suggest_alias(&template("", "team/admin.git", false)), "admin");
+        let reserved = vec!["list".to_string()];
+        let taken = vec!["list-template".to_string(), "admin".into(), "admin-2".into()];
+        assert_eq!(unique_alias("list", &reserved, &taken), "list-template-2");
+        assert_eq!(unique_alias("admin", &reserved, &taken), "admin-3");
+        assert_eq!(unique_alias("fresh", &reserved, &taken), "fresh");
+
+        assert_eq!(suggest_repo("github.com/team/x/").as_deref(), Some("team/x"));
+        assert_eq!(suggest_repo("git@github.com:team/x.git").as_deref(), Some("team/x"));
+        assert_eq!(suggest_repo("team/x"), None);
+        assert_eq!(suggest_repo("not a repo"), None);
+    }
+}
@@ -0,0 +1,92 @@
+use crate::commands::list::{save_with_history, templates_path};
+use crate::error::ActionError;
+use crate::i18n::tr;
+use crate::journal::{self, JournalEntry};
+use crate::ui::OutputFormat;
+use serde::Serialize;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// 修改历史中的一条记录，用于 `registry log` 的输出，不包含修改前的模板列表
+#[derive(Debug, Serialize)]
+pub struct LogEntry {
+    pub id: u64,
+    pub time: u64,
+    pub user: String,
+    pub command: String,
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub changed: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub reverts: Vec<u64>,
+    /// 是否已被撤销
+    pub undone: bool,
+}
+
+impl LogEntry {
+    /// 距今的秒数
+    pub fn age(&self) -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs())
+            .saturating_sub(self.time)
+    }
+}
+
+/// 查看最近的修改记录，从新到旧排列
+pub fn log(limit: usize) -> Result<(), ActionError> {
+    let entries = journal::load(&templates_path());
+    let undone = journal::undone_ids(&entries);
+    let log: Vec<LogEntry> = entries
+        .into_iter()
+        .rev()
+        .take(limit)
+        .map(|e| LogEntry {
+            undone: undone.contains(&e.id),
+            id: e.id,
+            time: e.time,
+            user: e.user,
+            command: e.command,
+            added: e.added,
+            removed: e.removed,
+            changed: e.changed,
+            reverts: e.reverts,
+        })
+        .collect();
+    match crate::ui::output_format() {
+        OutputFormat::Table if log.is_empty() => crate::ui::print_info(&tr!("history.empty")),
+        OutputFormat::Table => {
+            crate::ui::print_title(&tr!("history.title"));
+            crate::ui::print_history_table(&log);
+        }
+        OutputFormat::Plain => {
+            for entry in &log {
+                println!("{}\t{}\t{}\t{}", entry.id, entry.time, entry.user, entry.command);
+            }
+        }
+        _ => crate::ui::print_data(&log)?,
+    }
+    Ok(())
+}
+
+/// 撤销最近的 `steps` 次修改，恢复到其中最早一次修改前的状态
+///
+/// 撤销本身也会记录到历史中，已撤销的记录不会再次撤销
+pub fn undo(steps: usize) -> Result<(), ActionError> {
+    let entries = journal::load(&templates_path());
+    let candidates: Vec<&JournalEntry> = journal::undo_candidates(&entries, steps);
+    let Some(oldest) = candidates.last() else {
+        return Err(ActionError::Other(tr!("history.nothing_to_undo")));
+    };
+    if candidates.len() < steps {
+        return Err(ActionError::Other(tr!("history.not_enough", count = candidates.len())));
+    }
+
+    let spinner = crate::ui::create_spinner(&tr!("list.saving"));
+    let result = save_with_history(&oldest.before, candidates.iter().map(|e| e.id).collect());
+    spinner.finish_and_clear();
+    result?;
+    for entry in &candidates {
+        crate::ui::print_success(&tr!("history.undone", id = entry.id, command = entry.command));
+    }
+    Ok(())
+}
@@ -1,121 +1,258 @@
-use crate::commands::list::{find_by_alias, load_templates, save_templates, TemplateInfo};
+use crate::commands::list::{find_by_alias, load_templates_for_update, save_templates, TemplateInfo};
 use crate::error::ActionError;
-use colored::*;
+use crate::i18n::tr;
 
 /// 初始化命令参数结构体
 pub struct InitArgs {
     /// 是否强制覆盖已有模板
     pub force: bool,
+    /// 是否移除已弃用的内置模板，并把默认模板迁移到替代模板
+    pub migrate: bool,
+}
+
+/// 内置模板，描述与弃用原因一样按当前语言写入
+fn builtin(alias: &str, repo: &str, description: String, is_default: bool, tags: &[&str]) -> TemplateInfo {
+    TemplateInfo {
+        alias: alias.to_string(),
+        repo: repo.to_string(),
+        description,
+        is_default,
+        tags: tags.iter().map(|t| t.to_string()).collect(),
+        ..Default::default()
+    }
 }
 
 /// 常用Vue模板列表
-const DEFAULT_TEMPLATES: &[(&str, &str, &str, bool)] = &[
-    (
-        "vue3-vite",
-        "vuejs/create-vue",
-        "Vue 3 + Vite官方模板",
-        true,
-    ),
-    ("vue2", "vuejs/vue-cli", "Vue 2官方CLI模板", false),
-    ("nuxt3", "nuxt/starter", "Nuxt 3入门模板", false),
-    (
-        "vue3-ts",
-        "vuejs-templates/webpack-simple",
-        "Vue 3 + TypeScript模板",
-        false,
-    ),
-    (
-        "vue-element",
-        "PanJiaChen/vue-element-admin",
-        "基于Element UI的后台管理模板",
-        false,
-    ),
-];
+pub fn builtin_templates() -> Vec<TemplateInfo> {
+    vec![
+        builtin("vue3-vite", "vuejs/create-vue", tr!("init.desc_vue3_vite"), true, &["vue3", "vite"]),
+        builtin("vue2", "vuejs/vue-cli", tr!("init.desc_vue2"), false, &["vue2"]),
+        builtin("nuxt3", "nuxt/starter", tr!("init.desc_nuxt3"), false, &["nuxt", "ssr", "vue3"]),
+        TemplateInfo {
+            deprecated: true,
+            replaced_by: Some("vue3-vite".to_string()),
+            deprecation_reason: Some(tr!("init.vue3_ts_deprecated")),
+            ..builtin(
+                "vue3-ts",
+                "vuejs-templates/webpack-simple",
+                tr!("init.desc_vue3_ts"),
+                false,
+                &["vue3", "ts"],
+            )
+        },
+        builtin(
+            "vue-element",
+            "PanJiaChen/vue-element-admin",
+            tr!("init.desc_vue_element"),
+            false,
+            &["admin", "vue2", "element"],
+        ),
+    ]
+}
 
 /// 运行初始化命令
 pub fn run(args: InitArgs) -> Result<(), ActionError> {
-    let mut templates = load_templates();
+    let mut templates = load_templates_for_update()?;
     let mut added_count = 0;
     let mut updated_count = 0;
+    let builtins = builtin_templates();
 
-    crate::ui::print_title("初始化常用Vue模板");
+    crate::ui::print_title(&tr!("init.title"));
 
-    let spinner = crate::ui::create_spinner("准备模板数据中...");
-    std::thread::sleep(std::time::Duration::from_millis(800)); // 增加视觉效果
-    spinner.finish_and_clear();
-
-    let pb = indicatif::ProgressBar::new(DEFAULT_TEMPLATES.len() as u64);
+    let pb = indicatif::ProgressBar::new(builtins.len() as u64);
     pb.set_style(indicatif::ProgressStyle::default_bar()
         .template("{spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {pos}/{len} ({percent}%)")
         .unwrap()
         .progress_chars("#>-"));
 
-    for (alias, repo, description, is_default) in DEFAULT_TEMPLATES {
-        pb.set_message(format!("处理模板: {}", alias));
+    for builtin in &builtins {
+        let alias = &builtin.alias;
+        pb.set_message(tr!("init.processing", alias = alias));
 
         // 检查是否已存在
-        if let Some(_) = find_by_alias(&templates, alias) {
+        if find_by_alias(&templates, alias).is_some() {
             if !args.force {
-                crate::ui::print_warning(&format!("已跳过：模板 '{}' 已存在", alias));
+                crate::ui::print_warning(&tr!("init.skipped", alias = alias));
                 pb.inc(1);
-                std::thread::sleep(std::time::Duration::from_millis(300)); // 慢一点，便于用户查看
                 continue;
             }
 
             // 更新现有模板
             // 如果当前模板要设为默认，先取消其他模板的默认状态
-            if *is_default {
+            if builtin.is_default {
                 templates.iter_mut().for_each(|t| t.is_default = false);
             }
 
-            for tpl in &mut templates {
-                if &tpl.alias == alias {
-                    tpl.repo = repo.to_string();
-                    tpl.description = description.to_string();
-                    tpl.is_default = *is_default;
-                    break;
-                }
+            if let Some(tpl) = templates.iter_mut().find(|t| &t.alias == alias) {
+                overwrite_builtin(tpl, builtin);
             }
             updated_count += 1;
-            crate::ui::print_info(&format!("已更新：模板 '{}'", alias));
+            crate::ui::print_info(&tr!("init.updated", alias = alias));
+        } else if builtin.deprecated {
+            // 新的模板库不再添加已弃用的内置模板
+            pb.inc(1);
+            continue;
         } else {
             // 如果当前模板要设为默认，先取消其他模板的默认状态
-            if *is_default {
+            if builtin.is_default {
                 templates.iter_mut().for_each(|t| t.is_default = false);
             }
 
             // 添加新模板
-            templates.push(TemplateInfo {
-                alias: alias.to_string(),
-                repo: repo.to_string(),
-                description: description.to_string(),
-                is_default: *is_default,
-            });
+            templates.push(builtin.clone());
             added_count += 1;
-            crate::ui::print_success(&format!("已添加：模板 '{}'", alias));
+            crate::ui::print_success(&tr!("init.added", alias = alias));
         }
 
         pb.inc(1);
-        std::thread::sleep(std::time::Duration::from_millis(200)); // 添加延迟以增强视觉效果
     }
 
     pb.finish_and_clear();
+
+    let migrated_count = if args.migrate {
+        migrate_deprecated(&mut templates, &builtins)
+    } else {
+        0
+    };
+
     // 保存更改
-    let save_spinner = crate::ui::create_spinner("保存模板配置...");
-    save_templates(&templates);
-    std::thread::sleep(std::time::Duration::from_millis(800)); // 增强视觉效果
-    save_spinner.finish_with_message("模板配置已保存".to_string());
+    let save_spinner = crate::ui::create_spinner(&tr!("init.saving"));
+    save_templates(&templates)?;
+    save_spinner.finish_with_message(tr!("init.saved"));
 
-    crate::ui::print_title("初始化完成");
-    println!("{} 添加了 {} 个模板", "✓".bright_green(), added_count);
-    println!("{} 更新了 {} 个模板", "✓".bright_green(), updated_count);
+    crate::ui::print_title(&tr!("init.done_title"));
+    crate::ui::print_success(&tr!("init.added_count", count = added_count));
+    crate::ui::print_success(&tr!("init.updated_count", count = updated_count));
+    if args.migrate {
+        crate::ui::print_success(&tr!("init.migrated_count", count = migrated_count));
+    }
 
     // 立即显示所有模板
-    if added_count > 0 || updated_count > 0 {
-        println!();
-        crate::ui::print_info("已添加的模板如下:");
-        crate::ui::print_template_table(&templates);
+    if crate::ui::decorated() && (added_count > 0 || updated_count > 0 || migrated_count > 0) {
+        crate::ui::print_info(&tr!("init.list_below"));
+        crate::ui::print_template_table(&crate::commands::list::group_by_tag(templates), true);
     }
 
     Ok(())
 }
+
+/// 用内置模板覆盖同名模板中内置模板负责的字段: 仓库、描述、默认状态和弃用标记
+///
+/// 标签、固定的 ref、版本约束和元数据等用户补充的字段保留
+fn overwrite_builtin(tpl: &mut TemplateInfo, builtin: &TemplateInfo) {
+    tpl.repo = builtin.repo.clone();
+    tpl.description = builtin.description.clone();
+    tpl.is_default = builtin.is_default;
+    if builtin.deprecated {
+        tpl.deprecated = true;
+        tpl.replaced_by = builtin.replaced_by.clone();
+        tpl.deprecation_reason = builtin.deprecation_reason.clone();
+    }
+}
+
+/// 移除已弃用的内置模板，默认状态和其它模板的替代关系转移到替代模板
+///
+/// 同名但已改为指向其它仓库的模板属于用户自己的模板，不迁移。返回移除的模板数量
+fn migrate_deprecated(templates: &mut Vec<TemplateInfo>, builtins: &[TemplateInfo]) -> usize {
+    let mut migrated = 0;
+    for builtin in builtins.iter().filter(|b| b.deprecated) {
+        let Some(index) = templates.iter().position(|t| t.alias == builtin.alias) else {
+            continue;
+        };
+        if templates[index].repo != builtin.repo {
+            crate::ui::print_warning(&tr!(
+                "init.migrate_customized",
+                alias = builtin.alias,
+                repo = templates[index].repo
+            ));
+            continue;
+        }
+        // 没有可用的替代模板时保留，避免丢失默认模板
+        let Some(replacement) = builtin
+            .replaced_by
+            .clone()
+            .filter(|r| find_by_alias(templates, r).is_some())
+        else {
+            crate::ui::print_warning(&tr!("init.migrate_skipped", alias = builtin.alias));
+            continue;
+        };
+        let removed = templates.remove(index);
+        for tpl in templates.iter_mut() {
+            if removed.is_default {
+                tpl.is_default = tpl.alias == replacement;
+            }
+            if tpl.replaced_by.as_deref() == Some(removed.alias.as_str()) {
+                tpl.replaced_by = Some(replacement.clone());
+            }
+        }
+        migrated += 1;
+        crate::ui::print_success(&tr!("init.migrated", alias = removed.alias, replacement = replacement));
+    }
+    migrated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::list::test_template as template;
+
+    #[test]
+    fn force_keeps_user_fields() {
+        let builtins = builtin_templates();
+        let vite = builtins.iter().find(|b| b.alias == "vue3-vite").unwrap();
+        let mut tpl = TemplateInfo {
+            repo: "team/vite".into(),
+            tags: vec!["mine".into()],
+            git_ref: Some("v3".into()),
+            version_req: Some("^3".into()),
+            replaced_by: Some("nuxt3".into()),
+            ..template("vue3-vite")
+        };
+        tpl.meta.vue = Some("3.4".into());
+        let kept = tpl.clone();
+        overwrite_builtin(&mut tpl, vite);
+        assert_eq!((tpl.repo.as_str(), tpl.is_default), (vite.repo.as_str(), true));
+        assert_eq!(tpl.description, vite.description);
+        assert_eq!(tpl.tags, kept.tags);
+        assert_eq!((tpl.git_ref, tpl.version_req), (kept.git_ref, kept.version_req));
+        assert_eq!(tpl.replaced_by, kept.replaced_by);
+        assert_eq!(tpl.meta, kept.meta);
+
+        // 已弃用的内置模板覆盖弃用标记
+        let deprecated = builtins.iter().find(|b| b.deprecated).unwrap();
+        let mut tpl = TemplateInfo {
+            tags: vec!["mine".into()],
+            ..template(&deprecated.alias)
+        };
+        overwrite_builtin(&mut tpl, deprecated);
+        assert!(tpl.deprecated);
+        assert_eq!(tpl.replaced_by, deprecated.replaced_by);
+        assert_eq!(tpl.deprecation_reason, deprecated.deprecation_reason);
+        assert_eq!(tpl.tags, ["mine"]);
+    }
+
+    #[test]
+    fn migrates_only_unmodified_builtins() {
+        let builtins = builtin_templates();
+        let deprecated = builtins.iter().find(|b| b.deprecated).unwrap();
+        let mut old = deprecated.clone();
+        old.is_default = true;
+        let mut pointing = template("legacy");
+        pointing.replaced_by = Some(old.alias.clone());
+        let mut templates = vec![old.clone(), template("vue3-vite"), pointing];
+        assert_eq!(migrate_deprecated(&mut templates, &builtins), 1);
+        let aliases: Vec<_> = templates.iter().map(|t| t.alias.as_str()).collect();
+        assert_eq!(aliases, ["vue3-vite", "legacy"]);
+        assert!(templates[0].is_default);
+        assert_eq!(templates[1].replaced_by.as_deref(), Some("vue3-vite"));
+
+        // 用户改过仓库地址的同名模板保留
+        let customized = TemplateInfo {
+            repo: "team/vue3-ts".into(),
+            ..old
+        };
+        let mut templates = vec![customized.clone(), template("vue3-vite")];
+        assert_eq!(migrate_deprecated(&mut templates, &builtins), 0);
+        assert_eq!(templates[0], customized);
+    }
+}
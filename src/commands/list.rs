@@ -1,35 +1,351 @@
-use colored::*;
-use dialoguer::{theme::ColorfulTheme, Confirm, Input, Select};
+use crate::error::ActionError;
+use crate::i18n::tr;
+use crate::ui::OutputFormat;
+use crate::utils::{edit_distance, normalize_gh_url};
+use dialoguer::{theme::ColorfulTheme, Select};
 use serde::{Deserialize, Serialize};
 use std::fs;
+use std::path::Path;
+use std::str::FromStr;
 
-/// 模板文件路径，统一管理
+/// 默认模板文件路径
 pub const TEMPLATE_PATH: &str = "templates.yaml";
 
+/// 模板文件路径，优先使用配置中的 registry.path
+pub fn templates_path() -> String {
+    crate::config::get()
+        .registry
+        .path
+        .clone()
+        .unwrap_or_else(|| TEMPLATE_PATH.to_string())
+}
+
 /// 模板结构体
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct TemplateInfo {
     pub alias: String,
     pub repo: String,
+    /// 固定的分支或标签，为空时使用远程默认分支
+    #[serde(rename = "ref", default, skip_serializing_if = "Option::is_none")]
+    pub git_ref: Option<String>,
+    /// semver 版本约束，例如 `^2.1`，未固定 ref 时克隆满足约束的最新标签
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version_req: Option<String>,
     pub description: String,
+    /// 共享的模板库文档中可以省略
+    #[serde(default)]
     pub is_default: bool,
+    /// 标签，例如 vue3、ts、admin、ssr
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// 技术栈、许可证等结构化信息
+    #[serde(default, skip_serializing_if = "TemplateMeta::is_empty")]
+    pub meta: TemplateMeta,
+    /// 是否已弃用
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub deprecated: bool,
+    /// 替代模板的别名
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replaced_by: Option<String>,
+    /// 弃用原因
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deprecation_reason: Option<String>,
+    /// 来源的订阅名称，本地模板为空。只在合并时设置，不从文件读取
+    #[serde(default, skip_deserializing, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+}
+
+/// `update` 的修改内容，未设置的字段保持不变
+#[derive(Debug, Default)]
+pub struct TemplateUpdate {
+    pub repo: Option<String>,
+    /// 空字符串表示取消固定
+    pub git_ref: Option<String>,
+    /// 空字符串表示取消约束
+    pub version_req: Option<String>,
+    pub description: Option<String>,
+    pub is_default: Option<bool>,
+    /// 替换全部标签
+    pub tags: Option<Vec<String>>,
+    /// 合并到现有元数据
+    pub meta: TemplateMeta,
+    pub deprecated: Option<bool>,
+    pub replaced_by: Option<String>,
+    pub deprecation_reason: Option<String>,
+    /// 按键名修改的字段，在其它修改之后应用
+    pub fields: Vec<FieldAssignment>,
+}
+
+/// 模板的结构化元数据，均为可选
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TemplateMeta {
+    /// Vue 版本，例如 3.4.21
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vue: Option<String>,
+    /// UI 组件库，例如 element-plus
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ui_library: Option<String>,
+    /// 构建工具，例如 vite、webpack
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub build_tool: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub license: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub maintainer: Option<String>,
+}
+
+impl TemplateMeta {
+    /// 所有字段都未设置
+    pub fn is_empty(&self) -> bool {
+        *self == TemplateMeta::default()
+    }
+
+    /// 用 `other` 中已设置的字段覆盖当前值
+    pub fn merge(&mut self, other: TemplateMeta) {
+        let TemplateMeta { vue, ui_library, build_tool, license, maintainer } = other;
+        self.vue = vue.or(self.vue.take());
+        self.ui_library = ui_library.or(self.ui_library.take());
+        self.build_tool = build_tool.or(self.build_tool.take());
+        self.license = license.or(self.license.take());
+        self.maintainer = maintainer.or(self.maintainer.take());
+    }
+
+    /// 表格中显示的技术栈摘要，例如 `vue 3.4 · vite · element-plus`
+    pub fn stack(&self) -> String {
+        let vue = self.vue.as_ref().map(|v| format!("vue {}", v));
+        [vue.as_deref(), self.build_tool.as_deref(), self.ui_library.as_deref()]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join(" · ")
+    }
+}
+
+/// 可用于筛选和排序的模板字段
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum TemplateField {
+    Alias,
+    Vue,
+    Ui,
+    Build,
+    License,
+    Maintainer,
+}
+
+impl TemplateField {
+    /// 取模板中该字段的值
+    pub fn value(self, tpl: &TemplateInfo) -> Option<&str> {
+        match self {
+            TemplateField::Alias => Some(&tpl.alias),
+            TemplateField::Vue => tpl.meta.vue.as_deref(),
+            TemplateField::Ui => tpl.meta.ui_library.as_deref(),
+            TemplateField::Build => tpl.meta.build_tool.as_deref(),
+            TemplateField::License => tpl.meta.license.as_deref(),
+            TemplateField::Maintainer => tpl.meta.maintainer.as_deref(),
+        }
+    }
+}
+
+/// 字段筛选条件 `FIELD=VALUE`，按前缀匹配且不区分大小写 (例如 `vue=3`)
+#[derive(Debug, Clone)]
+pub struct FieldFilter {
+    pub field: TemplateField,
+    pub value: String,
+}
+
+impl FieldFilter {
+    pub fn matches(&self, tpl: &TemplateInfo) -> bool {
+        self.field
+            .value(tpl)
+            .is_some_and(|v| v.to_lowercase().starts_with(&self.value.to_lowercase()))
+    }
+}
+
+impl FromStr for FieldFilter {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (field, value) = s
+            .split_once('=')
+            .ok_or_else(|| tr!("list.invalid_field_filter", filter = s))?;
+        let field = <TemplateField as clap::ValueEnum>::from_str(field.trim(), true)
+            .map_err(|_| tr!("list.invalid_field_filter", filter = s))?;
+        Ok(FieldFilter {
+            field,
+            value: value.trim().to_string(),
+        })
+    }
+}
+
+/// `update --field` 的赋值 `KEY=VALUE`，元数据字段带 `meta.` 前缀 (例如 `meta.vue=3`)
+#[derive(Debug, Clone)]
+pub struct FieldAssignment {
+    pub key: String,
+    pub value: String,
+}
+
+impl FromStr for FieldAssignment {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (key, value) = s
+            .split_once('=')
+            .ok_or_else(|| tr!("list.invalid_field_assignment", assignment = s))?;
+        Ok(FieldAssignment {
+            key: key.trim().to_string(),
+            value: value.trim().to_string(),
+        })
+    }
+}
+
+/// `show` 的筛选条件
+#[derive(Debug, Default)]
+pub struct TemplateFilter {
+    /// 按别名、描述和标签模糊匹配，结果按相关度排序
+    pub query: Option<String>,
+    /// 必须包含的全部标签
+    pub tags: Vec<String>,
+    /// 只保留默认模板
+    pub default_only: bool,
+    /// 字段筛选条件，需全部满足
+    pub fields: Vec<FieldFilter>,
+    /// 按字段排序，未设置该字段的模板排在最后
+    pub sort: Option<TemplateField>,
+}
+
+impl TemplateFilter {
+    /// 是否设置了任何筛选条件
+    pub fn is_empty(&self) -> bool {
+        self.query.is_none() && self.tags.is_empty() && !self.default_only && self.fields.is_empty()
+    }
+
+    /// 结果是否按标签分组 (没有指定关键字和排序时)
+    pub fn grouped(&self) -> bool {
+        self.query.is_none() && self.sort.is_none()
+    }
+
+    /// 筛选模板，指定排序字段时按字段排序，有关键字时按相关度排序，否则按标签分组
+    pub fn apply(&self, templates: Vec<TemplateInfo>) -> Vec<TemplateInfo> {
+        let tags = normalize_tags(self.tags.clone());
+        let templates: Vec<TemplateInfo> = templates
+            .into_iter()
+            .filter(|t| !self.default_only || t.is_default)
+            .filter(|t| tags.iter().all(|tag| t.tags.contains(tag)))
+            .filter(|t| self.fields.iter().all(|f| f.matches(t)))
+            .collect();
+        let mut templates = match &self.query {
+            Some(query) => fuzzy_find(&templates, query).into_iter().cloned().collect(),
+            None if self.sort.is_some() => templates,
+            None => group_by_tag(templates),
+        };
+        if let Some(field) = self.sort {
+            // 稳定排序，值相同的模板保持相关度顺序
+            templates.sort_by(|a, b| match (field.value(a), field.value(b)) {
+                (Some(a), Some(b)) => a.to_lowercase().cmp(&b.to_lowercase()),
+                (a, b) => b.is_some().cmp(&a.is_some()),
+            });
+        }
+        templates
+    }
+}
+
+/// 标签统一为小写并去重
+pub fn normalize_tags(tags: Vec<String>) -> Vec<String> {
+    let mut result: Vec<String> = Vec::new();
+    for tag in tags {
+        let tag = tag.trim().to_lowercase();
+        if !tag.is_empty() && !result.contains(&tag) {
+            result.push(tag);
+        }
+    }
+    result
+}
+
+/// 模板所属分组: 第一个标签，没有标签时为空
+pub fn group_name(tpl: &TemplateInfo) -> &str {
+    tpl.tags.first().map(String::as_str).unwrap_or("")
+}
+
+/// 按分组排序，组内保持原有顺序，未打标签的排在最后
+pub fn group_by_tag(mut templates: Vec<TemplateInfo>) -> Vec<TemplateInfo> {
+    templates.sort_by(|a, b| {
+        let (a, b) = (group_name(a), group_name(b));
+        a.is_empty().cmp(&b.is_empty()).then_with(|| a.cmp(b))
+    });
+    templates
 }
 
 /// 加载模板
+///
+/// 文件无法解析或违反模板库约束时给出警告 (每次运行只提示一次)，无法解析时视为空
 pub fn load_templates() -> Vec<TemplateInfo> {
-    fs::read_to_string(TEMPLATE_PATH)
-        .ok()
-        .and_then(|content| serde_yaml::from_str(&content).ok())
-        .unwrap_or_default()
+    match read_templates() {
+        Ok(templates) => {
+            crate::commands::doctor::warn_once(&templates);
+            templates
+        }
+        Err(e) => {
+            crate::commands::doctor::warn_unreadable(&e);
+            Vec::new()
+        }
+    }
+}
+
+/// 加载要修改的模板库，无法解析时报错，避免保存时覆盖原文件
+pub fn load_templates_for_update() -> Result<Vec<TemplateInfo>, ActionError> {
+    let templates = read_templates()?;
+    crate::commands::doctor::warn_once(&templates);
+    Ok(templates)
 }
 
-/// 保存模板
-pub fn save_templates(templates: &[TemplateInfo]) {
-    if let Ok(content) = serde_yaml::to_string(templates) {
-        let _ = fs::write(TEMPLATE_PATH, content);
+/// 读取模板库文件，文件不存在时为空，无法读取或解析时报错
+pub fn read_templates() -> Result<Vec<TemplateInfo>, ActionError> {
+    let path = templates_path();
+    let unreadable = |e: &dyn std::fmt::Display| ActionError::Other(tr!("list.unreadable", path = path, error = e));
+    match fs::read_to_string(&path) {
+        Ok(content) => Ok(serde_yaml::from_str::<Option<Vec<TemplateInfo>>>(&content)
+            .map_err(|e| unreadable(&e))?
+            .unwrap_or_default()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(unreadable(&e)),
     }
 }
 
+/// 加载本地模板和订阅的模板库，订阅的模板只读
+pub fn load_all_templates() -> Vec<TemplateInfo> {
+    let mut templates = load_templates();
+    templates.extend(crate::commands::registry::subscribed_templates());
+    templates
+}
+
+/// 本地模板库中不存在该别名时的错误: 订阅的模板提示只读，否则提示不存在
+fn not_local(templates: &[TemplateInfo], alias: &str) -> ActionError {
+    let all = load_all_templates();
+    match find_by_alias(&all, alias).and_then(|t| t.source.as_ref()) {
+        Some(source) => ActionError::Other(tr!("list.read_only", alias = alias, source = source)),
+        None => not_found(templates, alias),
+    }
+}
+
+/// 保存模板，并把变更记录到修改历史
+pub fn save_templates(templates: &[TemplateInfo]) -> Result<(), ActionError> {
+    save_with_history(templates, Vec::new())
+}
+
+/// 保存模板，`reverts` 为本次撤销的历史记录编号。历史写入失败只给出警告
+///
+/// 现有文件无法解析时不保存也不记录历史，避免覆盖其中的模板
+pub fn save_with_history(templates: &[TemplateInfo], reverts: Vec<u64>) -> Result<(), ActionError> {
+    let path = templates_path();
+    let before = read_templates()?;
+    let content = serde_yaml::to_string(templates)?;
+    fs::write(&path, content)?;
+    if let Err(e) = crate::journal::record(&path, &before, templates, reverts) {
+        crate::ui::print_warning(&tr!("history.write_failed", error = e));
+    }
+    Ok(())
+}
+
 /// 根据别名查找模板
 pub fn find_by_alias<'a>(templates: &'a [TemplateInfo], alias: &str) -> Option<&'a TemplateInfo> {
     templates.iter().find(|t| t.alias == alias)
@@ -40,278 +356,793 @@ pub fn get_default_template(templates: &[TemplateInfo]) -> Option<&TemplateInfo>
     templates.iter().find(|t| t.is_default)
 }
 
-/// 交互式展示模板
-pub fn show_templates_interactive() {
-    let templates = load_templates();
-    crate::ui::print_title("交互式模板管理");
-
-    if templates.is_empty() {
-        crate::ui::print_warning("没有模板，请先添加或运行 'cvue init' 初始化常用模板。");
-        return;
+/// 模糊匹配得分，越小越接近，不相关时返回 None
+///
+/// 依次为: 别名相同、别名前缀、别名包含、标签相同、描述包含、别名拼写相近
+fn match_score(tpl: &TemplateInfo, query: &str) -> Option<usize> {
+    let query = query.to_lowercase();
+    let alias = tpl.alias.to_lowercase();
+    if alias == query {
+        Some(0)
+    } else if alias.starts_with(&query) {
+        Some(1)
+    } else if alias.contains(&query) {
+        Some(2)
+    } else if tpl.tags.contains(&query) {
+        Some(3)
+    } else if tpl.description.to_lowercase().contains(&query) {
+        Some(4)
+    } else {
+        let distance = edit_distance(&alias, &query);
+        (distance <= (query.chars().count() / 3).max(2)).then_some(4 + distance)
     }
+}
 
-    // 准备模板列表
-    let template_options: Vec<String> = templates
+/// 按相关度排序的模糊匹配结果
+///
+/// 关键字可以用空格分隔多个词，每个词都必须匹配
+pub fn fuzzy_find<'a>(templates: &'a [TemplateInfo], query: &str) -> Vec<&'a TemplateInfo> {
+    let mut matches: Vec<_> = templates
         .iter()
-        .map(|t| {
-            format!(
-                "{} - {} {}",
-                t.alias.bright_cyan(),
-                t.description,
-                if t.is_default {
-                    "[默认]".bright_green()
-                } else {
-                    "".into()
-                }
-            )
+        .filter_map(|t| {
+            query
+                .split_whitespace()
+                .map(|term| match_score(t, term))
+                .sum::<Option<usize>>()
+                .map(|score| (score, t))
         })
-        .collect(); // 选择模板
-    println!("请选择要操作的模板：");
-    let theme = ColorfulTheme::default();
-    let selection = Select::with_theme(&theme)
-        .items(&template_options)
-        .default(0)
-        .interact();
-
-    if let Ok(index) = selection {
-        let template = &templates[index];
-
-        // 显示模板详情
-        crate::ui::print_template_detail(template); // 提供操作选项
-        let options = vec!["克隆此模板", "删除此模板", "更新此模板", "返回"];
-        println!("\n请选择要执行的操作：");
-        let operation = Select::with_theme(&theme)
-            .items(&options)
-            .default(0)
-            .interact();
-
-        if let Ok(op) = operation {
-            match op {
-                0 => {
-                    // 克隆模板
-                    println!("请输入目标目录名称：");
-                    let target: String = Input::with_theme(&theme)
-                        .interact_text()
-                        .unwrap_or_else(|_| "project".to_string());
-
-                    crate::commands::clone::run(crate::commands::clone::CloneArgs {
-                        template: Some(template.alias.clone()),
-                        target: Some(target),
-                        token: None,
-                    })
-                    .unwrap_or_else(|e| {
-                        crate::ui::print_error(&format!("克隆失败: {}", e));
-                    });
-                }
-                1 => {
-                    // 删除模板
-                    let confirm =
-                        crate::ui::confirm(&format!("确定要删除模板 '{}'吗?", template.alias))
-                            .unwrap_or(false);
-                    if confirm {
-                        remove_template(template.alias.clone());
-                    } else {
-                        crate::ui::print_info("已取消删除操作");
-                    }
-                }
-                2 => {
-                    // 更新模板
-                    println!("请选择要更新的属性：");
-                    let update_options = vec!["仓库地址", "描述", "默认状态", "全部更新", "取消"];
-                    let update_choice = Select::with_theme(&theme)
-                        .items(&update_options)
-                        .default(0)
-                        .interact();
-
-                    if let Ok(update_op) = update_choice {
-                        match update_op {
-                            0 => {
-                                // 更新仓库
-                                let repo: String = Input::with_theme(&theme)
-                                    .with_prompt("请输入新的仓库地址")
-                                    .with_initial_text(&template.repo)
-                                    .interact_text()
-                                    .unwrap_or_else(|_| template.repo.clone());
-                                update_template(template.alias.clone(), Some(repo), None, None);
-                            }
-                            1 => {
-                                // 更新描述
-                                let desc: String = Input::with_theme(&theme)
-                                    .with_prompt("请输入新的描述")
-                                    .with_initial_text(&template.description)
-                                    .interact_text()
-                                    .unwrap_or_else(|_| template.description.clone());
-                                update_template(template.alias.clone(), None, Some(desc), None);
-                            }
-                            2 => {
-                                // 更新默认状态
-                                let is_default = Confirm::with_theme(&theme)
-                                    .with_prompt("设为默认模板?")
-                                    .default(template.is_default)
-                                    .interact()
-                                    .unwrap_or(template.is_default);
-                                update_template(
-                                    template.alias.clone(),
-                                    None,
-                                    None,
-                                    Some(is_default),
-                                );
-                            }
-                            3 => {
-                                // 全部更新
-                                let repo: String = Input::with_theme(&theme)
-                                    .with_prompt("请输入新的仓库地址")
-                                    .with_initial_text(&template.repo)
-                                    .interact_text()
-                                    .unwrap_or_else(|_| template.repo.clone());
-                                let desc: String = Input::with_theme(&theme)
-                                    .with_prompt("请输入新的描述")
-                                    .with_initial_text(&template.description)
-                                    .interact_text()
-                                    .unwrap_or_else(|_| template.description.clone());
-
-                                let is_default = Confirm::with_theme(&theme)
-                                    .with_prompt("设为默认模板?")
-                                    .default(template.is_default)
-                                    .interact()
-                                    .unwrap_or(template.is_default);
-
-                                update_template(
-                                    template.alias.clone(),
-                                    Some(repo),
-                                    Some(desc),
-                                    Some(is_default),
-                                );
-                            }
-                            _ => {
-                                crate::ui::print_info("已取消更新操作");
-                            }
-                        }
-                    }
-                }
-                _ => {
-                    // 返回
-                    crate::ui::print_info("已退出");
-                }
-            }
+        .collect();
+    matches.sort_by(|(a, x), (b, y)| a.cmp(b).then_with(|| x.alias.cmp(&y.alias)));
+    matches.into_iter().map(|(_, t)| t).collect()
+}
+
+/// 模板不存在的错误，附带最接近的几个别名
+pub fn not_found(templates: &[TemplateInfo], alias: &str) -> ActionError {
+    ActionError::TemplateNotFound {
+        alias: alias.to_string(),
+        suggestions: fuzzy_find(templates, alias)
+            .iter()
+            .take(3)
+            .map(|t| t.alias.clone())
+            .collect(),
+    }
+}
+
+/// 别名以 `query` 开头 (不区分大小写) 的模板
+fn prefix_matches<'a>(templates: &'a [TemplateInfo], query: &str) -> Vec<&'a TemplateInfo> {
+    let prefix = query.to_lowercase();
+    templates
+        .iter()
+        .filter(|t| t.alias.to_lowercase().starts_with(&prefix))
+        .collect()
+}
+
+/// 按别名解析模板: 精确匹配优先，其次是唯一的前缀匹配
+///
+/// 多个模板匹配前缀时，终端中让用户选择，否则报错并列出这些别名
+pub fn resolve_alias<'a>(
+    templates: &'a [TemplateInfo],
+    query: &str,
+) -> Result<&'a TemplateInfo, ActionError> {
+    if let Some(tpl) = find_by_alias(templates, query) {
+        return Ok(tpl);
+    }
+    let candidates = prefix_matches(templates, query);
+    match candidates.as_slice() {
+        [] => Err(not_found(templates, query)),
+        [tpl] => {
+            crate::ui::print_info(&tr!("list.prefix_matched", query = query, alias = tpl.alias));
+            Ok(tpl)
+        }
+        _ if crate::ui::interactive() => {
+            let options: Vec<String> = candidates.iter().map(|t| crate::ui::template_option(t)).collect();
+            let selection = Select::with_theme(&ColorfulTheme::default())
+                .with_prompt(tr!("list.pick_match", query = query))
+                .items(&options)
+                .default(0)
+                .interact()
+                .map_err(|e| ActionError::Other(tr!("common.interaction_error", error = e)))?;
+            Ok(candidates[selection])
         }
+        _ => Err(ActionError::TemplateNotFound {
+            alias: query.to_string(),
+            suggestions: candidates.iter().map(|t| t.alias.clone()).collect(),
+        }),
     }
 }
 
 /// 展示模板
-pub fn show_templates() {
-    let templates = load_templates();
-    crate::ui::print_title("可用模板");
-    crate::ui::print_template_table(&templates);
+pub fn show_templates(filter: TemplateFilter) -> Result<(), ActionError> {
+    let templates = filter.apply(load_all_templates());
+    match crate::ui::output_format() {
+        OutputFormat::Table if templates.is_empty() && !filter.is_empty() => {
+            crate::ui::print_warning(&tr!("list.no_filter_match"));
+        }
+        OutputFormat::Table => {
+            crate::ui::print_title(&tr!("list.title"));
+            // 有关键字或排序时保持结果顺序，不分组
+            crate::ui::print_template_table(&templates, filter.grouped());
+        }
+        OutputFormat::Plain => {
+            for tpl in &templates {
+                println!("{}", crate::ui::template_plain_line(tpl));
+            }
+        }
+        _ => crate::ui::print_data(&templates)?,
+    }
+    Ok(())
 }
 
 /// 添加模板
-pub fn add_template(alias: String, repo: String, description: String, is_default: bool) {
-    let mut templates = load_templates();
+///
+/// `detect` 时从仓库的 package.json 推断元数据
+pub fn add_template(template: TemplateInfo, detect: bool) -> Result<(), ActionError> {
+    let mut templates = load_templates_for_update()?;
+    let TemplateInfo {
+        alias,
+        repo,
+        mut meta,
+        ..
+    } = template.clone();
 
-    let spinner = crate::ui::create_spinner("正在检查模板...");
+    check_new_alias(&templates, &alias)?;
+    normalize_gh_url(&repo)?;
+    if let Some(req) = &template.version_req {
+        crate::commands::refs::parse_req(req)?;
+    }
 
-    if find_by_alias(&templates, &alias).is_some() {
+    // 从 package.json 推断元数据，命令行显式指定的字段优先
+    if detect {
+        let spinner = crate::ui::create_spinner(&tr!("list.detecting"));
+        let detected = crate::detect::detect_meta(&repo);
         spinner.finish_and_clear();
-        crate::ui::print_warning(&format!("模板 '{}' 已存在，不能重复添加", alias));
-        return;
+        match detected {
+            Ok(mut detected) => {
+                detected.merge(meta);
+                meta = detected;
+                crate::ui::print_info(&tr!("list.detected", stack = meta.stack()));
+            }
+            Err(e) => crate::ui::print_warning(&tr!("list.detect_failed", error = e)),
+        }
     }
 
+    let spinner = crate::ui::create_spinner(&tr!("list.saving"));
+
     // 若设为默认，把其它全部设为非默认
-    if is_default {
+    if template.is_default {
         templates.iter_mut().for_each(|t| t.is_default = false);
     }
 
     templates.push(TemplateInfo {
-        alias: alias.clone(),
-        repo,
-        description,
-        is_default,
+        tags: normalize_tags(template.tags.clone()),
+        meta,
+        ..template
     });
 
-    spinner.set_message("正在保存模板...");
-    save_templates(&templates);
+    let result = save_templates(&templates);
     spinner.finish_and_clear();
+    result?;
 
-    crate::ui::print_success(&format!("模板 '{}' 添加成功！", alias));
+    crate::ui::print_success(&tr!("list.added", alias = alias));
+    Ok(())
 }
 
 /// 删除模板
-pub fn remove_template(alias: String) {
-    let mut templates = load_templates();
+pub fn remove_template(alias: String) -> Result<(), ActionError> {
+    let mut templates = load_templates_for_update()?;
     let old_len = templates.len();
 
-    let spinner = crate::ui::create_spinner(&format!("正在删除模板 '{}'...", alias));
-
     templates.retain(|t| t.alias != alias);
 
     if templates.len() == old_len {
-        spinner.finish_and_clear();
-        crate::ui::print_warning(&format!("未找到别名为 '{}' 的模板", alias));
-    } else {
-        save_templates(&templates);
-        spinner.finish_and_clear();
-        crate::ui::print_success(&format!("模板 '{}' 已删除", alias));
+        return Err(not_local(&templates, &alias));
     }
+
+    let spinner = crate::ui::create_spinner(&tr!("list.removing", alias = alias));
+    let result = save_templates(&templates);
+    spinner.finish_and_clear();
+    result?;
+
+    crate::ui::print_success(&tr!("list.removed", alias = alias));
+    Ok(())
 }
 
 /// 更新模板
-pub fn update_template(
-    alias: String,
-    repo: Option<String>,
-    description: Option<String>,
-    is_default: Option<bool>,
-) {
-    let mut templates = load_templates();
-    let mut found = false;
-
-    let spinner = crate::ui::create_spinner(&format!("正在更新模板 '{}'...", alias));
+pub fn update_template(alias: String, update: TemplateUpdate) -> Result<(), ActionError> {
+    let templates = load_templates_for_update()?;
+    let Some(mut tpl) = find_by_alias(&templates, &alias).cloned() else {
+        return Err(not_local(&templates, &alias));
+    };
+    let TemplateUpdate {
+        repo,
+        git_ref,
+        version_req,
+        description,
+        is_default,
+        tags,
+        meta,
+        deprecated,
+        replaced_by,
+        deprecation_reason,
+        fields,
+    } = update;
 
-    // 若设为默认，全部取消默认
-    if matches!(is_default, Some(true)) {
-        templates.iter_mut().for_each(|t| t.is_default = false);
+    if let Some(r) = repo {
+        tpl.repo = r;
+    }
+    if git_ref.is_some() {
+        tpl.git_ref = git_ref;
+    }
+    if version_req.is_some() {
+        tpl.version_req = version_req;
+    }
+    if let Some(d) = description {
+        tpl.description = d;
+    }
+    if let Some(df) = is_default {
+        tpl.is_default = df;
     }
+    if let Some(tags) = tags {
+        tpl.tags = tags;
+    }
+    tpl.meta.merge(meta);
+    if let Some(deprecated) = deprecated {
+        tpl.deprecated = deprecated;
+        // 取消弃用时一并清除替代模板和原因
+        if !deprecated {
+            tpl.replaced_by = None;
+            tpl.deprecation_reason = None;
+        }
+    }
+    if replaced_by.is_some() {
+        tpl.replaced_by = replaced_by;
+    }
+    if deprecation_reason.is_some() {
+        tpl.deprecation_reason = deprecation_reason;
+    }
+    for field in fields {
+        tpl = set_field(&tpl, &field.key, &field.value)?;
+    }
+    let new_alias = tpl.alias.clone();
+    let templates = apply_edit(templates, &alias, tpl)?;
 
-    for tpl in &mut templates {
-        if tpl.alias == alias {
-            if let Some(r) = repo {
-                tpl.repo = r;
+    let spinner = crate::ui::create_spinner(&tr!("list.updating", alias = alias));
+    let result = save_templates(&templates);
+    spinner.finish_and_clear();
+    result?;
+
+    crate::ui::print_success(&tr!("list.updated", alias = new_alias));
+    Ok(())
+}
+
+/// 重命名模板，其它模板中指向它的替代模板引用一并修改
+pub fn rename_template(old: String, new: String) -> Result<(), ActionError> {
+    let templates = load_templates_for_update()?;
+    let Some(tpl) = find_by_alias(&templates, &old).cloned() else {
+        return Err(not_local(&templates, &old));
+    };
+    let templates = apply_edit(
+        templates,
+        &old,
+        TemplateInfo {
+            alias: new.clone(),
+            ..tpl
+        },
+    )?;
+
+    let spinner = crate::ui::create_spinner(&tr!("list.saving"));
+    let result = save_templates(&templates);
+    spinner.finish_and_clear();
+    result?;
+
+    crate::ui::print_success(&tr!("list.renamed", old = old, new = new));
+    Ok(())
+}
+
+/// 在编辑器中以 YAML 编辑模板，保存后校验，校验失败时可以重新编辑
+pub fn edit_template(alias: String) -> Result<(), ActionError> {
+    let templates = load_templates_for_update()?;
+    let Some(original) = find_by_alias(&templates, &alias).cloned() else {
+        return Err(not_local(&templates, &alias));
+    };
+    let dir = crate::utils::private_temp_dir("cvue-edit")?;
+    let path = dir.join("template.yaml");
+    let result = fs::write(&path, serde_yaml::to_string(&original)?)
+        .map_err(ActionError::from)
+        .and_then(|_| edit_until_valid(&path, templates, &original));
+    let _ = fs::remove_dir_all(&dir);
+    result
+}
+
+/// 反复打开编辑器直到内容通过校验、没有修改或用户放弃，放弃时返回校验错误
+fn edit_until_valid(path: &Path, templates: Vec<TemplateInfo>, original: &TemplateInfo) -> Result<(), ActionError> {
+    loop {
+        crate::utils::open_in_editor(path)?;
+        let edited = serde_yaml::from_str::<TemplateInfo>(&fs::read_to_string(path)?)
+            .map_err(ActionError::from)
+            .and_then(|edited| {
+                if edited == *original {
+                    return Ok(None);
+                }
+                let alias = edited.alias.clone();
+                apply_edit(templates.clone(), &original.alias, edited).map(|t| Some((alias, t)))
+            });
+        match edited {
+            Ok(None) => {
+                crate::ui::print_info(&tr!("list.edit_unchanged"));
+                return Ok(());
             }
-            if let Some(d) = description {
-                tpl.description = d;
+            Ok(Some((alias, templates))) => {
+                save_templates(&templates)?;
+                crate::ui::print_success(&tr!("list.updated", alias = alias));
+                return Ok(());
             }
-            if let Some(df) = is_default {
-                tpl.is_default = df;
+            Err(e) => {
+                if !crate::ui::interactive() || !crate::ui::confirm(&tr!("list.edit_again", error = e))? {
+                    return Err(ActionError::Other(tr!("list.edit_invalid", error = e)));
+                }
             }
-            found = true;
-            break;
         }
     }
+}
 
-    if found {
-        save_templates(&templates);
-        spinner.finish_and_clear();
-        crate::ui::print_success(&format!("模板 '{}' 更新成功", alias));
+/// 可以用 `--field` 修改的字段，与模板文件中的键名一致
+pub fn editable_fields() -> Vec<String> {
+    let meta = crate::utils::struct_fields::<TemplateMeta>();
+    crate::utils::struct_fields::<TemplateInfo>()
+        .iter()
+        .flat_map(|field| match *field {
+            "meta" => meta.iter().map(|m| format!("meta.{}", m)).collect(),
+            _ => vec![field.to_string()],
+        })
+        .collect()
+}
+
+/// 按键名修改模板的一个字段
+///
+/// 值按 YAML 解析 (例如 `true`、`[a, b]`)，类型不符时按字符串处理；值为空时清除该字段
+pub fn set_field(tpl: &TemplateInfo, key: &str, value: &str) -> Result<TemplateInfo, ActionError> {
+    let fields = editable_fields();
+    if !fields.iter().any(|f| f == key) {
+        return Err(ActionError::Other(tr!("list.unknown_field", field = key, fields = fields.join(", "))));
+    }
+    let parent = key.split_once('.').map(|(parent, _)| parent);
+    let name = key.rsplit('.').next().unwrap_or(key);
+    let doc = serde_yaml::to_value(tpl)?;
+    let with_value = |value: Option<serde_yaml::Value>| -> Result<TemplateInfo, serde_yaml::Error> {
+        let mut doc = doc.clone();
+        let mut target = doc.as_mapping_mut().expect("模板总是序列化为映射");
+        if let Some(parent) = parent {
+            target = target
+                .entry(parent.into())
+                .or_insert_with(|| serde_yaml::Value::Mapping(Default::default()))
+                .as_mapping_mut()
+                .expect("元数据总是序列化为映射");
+        }
+        match value {
+            Some(value) => target.insert(name.into(), value),
+            None => target.remove(name),
+        };
+        serde_yaml::from_value(doc)
+    };
+    let result = if value.is_empty() {
+        with_value(None)
     } else {
-        spinner.finish_and_clear();
-        crate::ui::print_warning(&format!("未找到别名为 '{}' 的模板", alias));
+        let parsed = serde_yaml::from_str(value).unwrap_or_else(|_| serde_yaml::Value::from(value));
+        with_value(Some(parsed)).or_else(|e| with_value(Some(serde_yaml::Value::from(value))).map_err(|_| e))
+    };
+    result.map_err(|e| ActionError::Other(tr!("list.invalid_field_value", field = key, error = e)))
+}
+
+/// 检查新别名: 不能为空、不能包含 `/`、不能与本地模板或子命令重复
+pub fn check_new_alias(templates: &[TemplateInfo], alias: &str) -> Result<(), ActionError> {
+    if alias.trim().is_empty() {
+        return Err(ActionError::Other(tr!("list.alias_empty")));
+    }
+    if find_by_alias(templates, alias).is_some() {
+        return Err(ActionError::AliasExists(alias.to_string()));
+    }
+    // `/` 留给订阅模板库的命名空间
+    if alias.contains('/') {
+        return Err(ActionError::Other(tr!("list.alias_has_slash", alias = alias)));
+    }
+    if crate::cli::reserved_names().iter().any(|name| name == alias) {
+        return Err(ActionError::Other(tr!("list.alias_reserved", alias = alias)));
+    }
+    Ok(())
+}
+
+/// 校验编辑后的模板并替换别名为 `alias` 的原模板，返回新的模板列表
+///
+/// 只校验有变化的仓库地址、版本约束和替代模板；别名改变时检查冲突并同步其它模板的
+/// 替代模板引用；设为默认时取消其它模板的默认
+pub fn apply_edit(
+    mut templates: Vec<TemplateInfo>,
+    alias: &str,
+    edited: TemplateInfo,
+) -> Result<Vec<TemplateInfo>, ActionError> {
+    let index = templates
+        .iter()
+        .position(|t| t.alias == alias)
+        .ok_or_else(|| not_local(&templates, alias))?;
+    let original = templates[index].clone();
+    let edited = TemplateInfo {
+        tags: normalize_tags(edited.tags),
+        git_ref: edited.git_ref.filter(|r| !r.is_empty()),
+        version_req: edited.version_req.filter(|r| !r.is_empty()),
+        ..edited
+    };
+
+    if edited.alias != alias {
+        check_new_alias(&templates, &edited.alias)?;
+    }
+    if edited.repo != original.repo {
+        normalize_gh_url(&edited.repo)?;
+    }
+    if let Some(req) = edited.version_req.as_ref().filter(|r| **r != original.version_req.as_deref().unwrap_or_default()) {
+        crate::commands::refs::parse_req(req)?;
+    }
+    // 替代模板必须存在且不能是自身
+    if let Some(replacement) = edited.replaced_by.as_ref().filter(|r| Some(*r) != original.replaced_by.as_ref()) {
+        if *replacement == edited.alias || replacement == alias {
+            return Err(ActionError::Other(tr!("list.replaced_by_self", alias = edited.alias)));
+        }
+        // 替代模板也可以是订阅的模板
+        let all = load_all_templates();
+        if find_by_alias(&all, replacement).is_none() {
+            return Err(not_found(&all, replacement));
+        }
+    }
+
+    if edited.is_default {
+        templates.iter_mut().for_each(|t| t.is_default = false);
     }
+    if edited.alias != alias {
+        for tpl in templates.iter_mut().filter(|t| t.replaced_by.as_deref() == Some(alias)) {
+            tpl.replaced_by = Some(edited.alias.clone());
+        }
+    }
+    templates[index] = edited;
+    Ok(templates)
+}
+
+/// 按别名、描述和标签搜索模板，结果按相关度排序
+pub fn search_templates(terms: Vec<String>) -> Result<(), ActionError> {
+    let query = terms.join(" ");
+    let templates = load_all_templates();
+    let matches = fuzzy_find(&templates, &query);
+    match crate::ui::output_format() {
+        OutputFormat::Table if matches.is_empty() => {
+            crate::ui::print_warning(&tr!("list.no_match", query = query));
+        }
+        OutputFormat::Table => {
+            crate::ui::print_title(&tr!("list.search_title", query = query));
+            let matches: Vec<TemplateInfo> = matches.into_iter().cloned().collect();
+            crate::ui::print_template_table(&matches, false);
+        }
+        OutputFormat::Plain => {
+            for tpl in matches {
+                println!("{}", crate::ui::template_plain_line(tpl));
+            }
+        }
+        _ => crate::ui::print_data(&matches)?,
+    }
+    Ok(())
 }
 
 /// 获取模板
-pub fn get_template(alias: String) {
-    let templates = load_templates();
+pub fn get_template(alias: String, offline: bool) -> Result<(), ActionError> {
+    let templates = load_all_templates();
+    let tpl = resolve_alias(&templates, &alias)?;
+    match crate::ui::output_format() {
+        OutputFormat::Table => {
+            let upstream = upstream_info(tpl, offline);
+            crate::ui::print_template_detail(tpl, upstream.as_ref());
+        }
+        OutputFormat::Plain => println!("{}", crate::ui::template_plain_line(tpl)),
+        _ => crate::ui::print_data(&TemplateDetail {
+            template: tpl,
+            upstream: upstream_info(tpl, offline),
+        })?,
+    }
+    Ok(())
+}
+
+/// 模板详情，附带上游仓库信息，用于结构化输出
+#[derive(Serialize)]
+struct TemplateDetail<'a> {
+    #[serde(flatten)]
+    template: &'a TemplateInfo,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    upstream: Option<crate::github::RepoInfo>,
+}
+
+/// 读取上游仓库信息，失败时只给出警告
+pub fn upstream_info(tpl: &TemplateInfo, offline: bool) -> Option<crate::github::RepoInfo> {
+    let spinner = (!offline && crate::ui::decorated()).then(|| crate::ui::create_spinner(&tr!("list.fetching_upstream")));
+    let result = crate::github::repo_info(&tpl.repo, offline);
+    if let Some(spinner) = spinner {
+        spinner.finish_and_clear();
+    }
+    match result {
+        Ok(info) => info,
+        Err(e) => {
+            crate::ui::print_warning(&tr!("list.upstream_failed", error = e));
+            None
+        }
+    }
+}
+
+/// 测试用的模板: 仓库为 `team/<alias>`，描述与别名相同
+#[cfg(test)]
+pub fn test_template(alias: &str) -> TemplateInfo {
+    TemplateInfo {
+        alias: alias.into(),
+        repo: format!("team/{}", alias),
+        description: alias.into(),
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::test_template as template;
+
+    fn aliases(templates: &[&TemplateInfo]) -> Vec<String> {
+        templates.iter().map(|t| t.alias.clone()).collect()
+    }
+
+    #[test]
+    fn ranks_matches_by_kind() {
+        let mut by_tag = template("starter");
+        by_tag.tags = vec!["admin".into()];
+        let mut by_description = template("dashboard");
+        by_description.description = "Admin dashboard".into();
+        let templates = vec![
+            by_description,
+            template("vue-admin"),
+            by_tag,
+            template("admin-pro"),
+            template("admin"),
+            template("amdin-lite"),
+        ];
+        let tpl = |alias| find_by_alias(&templates, alias).unwrap();
+        assert_eq!(match_score(tpl("admin"), "ADMIN"), Some(0));
+        assert_eq!(match_score(tpl("admin-pro"), "admin"), Some(1));
+        assert_eq!(match_score(tpl("vue-admin"), "admin"), Some(2));
+        assert_eq!(match_score(tpl("starter"), "admin"), Some(3));
+        assert_eq!(match_score(tpl("dashboard"), "admin"), Some(4));
+        assert_eq!(
+            aliases(&fuzzy_find(&templates, "admin")),
+            ["admin", "admin-pro", "vue-admin", "starter", "dashboard"]
+        );
+    }
+
+    #[test]
+    fn breaks_ties_by_alias_and_requires_every_term() {
+        let mut vite = template("vite-ts");
+        vite.tags = vec!["ts".into()];
+        let mut nuxt = template("nuxt-ts");
+        nuxt.tags = vec!["ts".into(), "ssr".into()];
+        let templates = vec![vite, nuxt, template("webpack")];
+        assert_eq!(aliases(&fuzzy_find(&templates, "ts")), ["nuxt-ts", "vite-ts"]);
+        assert_eq!(aliases(&fuzzy_find(&templates, "ts ssr")), ["nuxt-ts"]);
+        // 空关键字不筛选，按别名排序
+        assert_eq!(aliases(&fuzzy_find(&templates, "")), ["nuxt-ts", "vite-ts", "webpack"]);
+    }
+
+    #[test]
+    fn suggests_only_close_spellings() {
+        assert_eq!(edit_distance("admin", "admin"), 0);
+        assert_eq!(edit_distance("amdin", "admin"), 2);
+        assert_eq!(edit_distance("", "vue"), 3);
+        assert_eq!(edit_distance("编辑", "编辑器"), 1);
 
-    let spinner = crate::ui::create_spinner(&format!("正在查找模板 '{}'...", alias));
+        let templates = vec![template("admin"), template("nuxt3"), template("vue3-vite"), template("vue2")];
+        // 短关键字最多允许两处差异，长关键字允许三分之一
+        assert_eq!(match_score(&templates[0], "amdin"), Some(6));
+        assert_eq!(match_score(&templates[0], "xyz"), None);
+        assert_eq!(match_score(&templates[2], "vue3-vitw"), Some(5));
+        assert_eq!(match_score(&templates[2], "vue-vote"), Some(6));
+        assert_eq!(match_score(&templates[2], "vue-react"), None);
+        match not_found(&templates, "vue") {
+            ActionError::TemplateNotFound { suggestions, .. } => assert_eq!(suggestions, ["vue2", "vue3-vite"]),
+            e => panic!("unexpected error: {e}"),
+        }
+        match not_found(&templates, "react") {
+            ActionError::TemplateNotFound { suggestions, .. } => assert!(suggestions.is_empty()),
+            e => panic!("unexpected error: {e}"),
+        }
+    }
+
+    #[test]
+    fn resolves_exact_alias_before_prefix() {
+        let templates = vec![template("vue"), template("vue3-vite"), template("Vue2"), template("nuxt3")];
+        assert_eq!(aliases(&prefix_matches(&templates, "VUE")), ["vue", "vue3-vite", "Vue2"]);
+        assert_eq!(resolve_alias(&templates, "vue").unwrap().alias, "vue");
+        assert_eq!(resolve_alias(&templates, "nu").unwrap().alias, "nuxt3");
+        assert!(prefix_matches(&templates, "react").is_empty());
+        assert!(matches!(
+            resolve_alias(&templates, "react"),
+            Err(ActionError::TemplateNotFound { .. })
+        ));
+    }
+
+    fn tagged(alias: &str, tags: &[&str], is_default: bool) -> TemplateInfo {
+        TemplateInfo {
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            is_default,
+            ..template(alias)
+        }
+    }
+
+    #[test]
+    fn filters_by_field_prefix() {
+        let mut tpl = template("admin");
+        tpl.meta.vue = Some("3.4.21".into());
+        tpl.meta.build_tool = Some("Vite".into());
+        let filter = |s: &str| s.parse::<FieldFilter>().unwrap();
+        assert!(filter("vue=3").matches(&tpl));
+        assert!(filter("vue=3.4").matches(&tpl));
+        assert!(!filter("vue=2").matches(&tpl));
+        assert!(filter("BUILD = vite").matches(&tpl));
+        // 未设置的字段不匹配
+        assert!(!filter("license=").matches(&tpl));
+        assert!(filter("alias=adm").matches(&tpl));
+        assert!("vue".parse::<FieldFilter>().is_err());
+        assert!("colour=red".parse::<FieldFilter>().is_err());
+    }
+
+    #[test]
+    fn sorts_by_field_with_missing_values_last() {
+        let mut templates: Vec<TemplateInfo> = ["c", "a", "b"].into_iter().map(template).collect();
+        templates[0].meta.license = Some("mit".into());
+        templates[2].meta.license = Some("Apache-2.0".into());
+        let sorted: Vec<_> = TemplateFilter {
+            sort: Some(TemplateField::License),
+            ..Default::default()
+        }
+        .apply(templates)
+        .into_iter()
+        .map(|t| t.alias)
+        .collect();
+        assert_eq!(sorted, ["b", "c", "a"]);
+    }
+
+    #[test]
+    fn normalizes_tags() {
+        let tags = vec![" Vue3 ".to_string(), "TS".into(), "vue3".into(), "".into(), "ts".into(), "admin".into()];
+        assert_eq!(normalize_tags(tags), ["vue3", "ts", "admin"]);
+    }
+
+    #[test]
+    fn groups_by_first_tag_keeping_order() {
+        let templates = vec![
+            tagged("plain", &[], false),
+            tagged("vite", &["vue3", "ts"], false),
+            tagged("nuxt", &["ssr"], false),
+            tagged("admin", &["admin", "vue3"], false),
+            tagged("vite-js", &["vue3"], false),
+        ];
+        let grouped: Vec<_> = group_by_tag(templates).into_iter().map(|t| t.alias).collect();
+        assert_eq!(grouped, ["admin", "nuxt", "vite", "vite-js", "plain"]);
+    }
+
+    #[test]
+    fn combines_tag_query_and_default_filters() {
+        let templates = vec![
+            tagged("vue3-admin", &["vue3", "admin"], false),
+            tagged("vue3-vite", &["vue3", "ts"], true),
+            tagged("vue3-ts-admin", &["vue3", "ts", "admin"], false),
+            tagged("nuxt-admin", &["ssr", "admin"], false),
+        ];
+        let apply = |filter: TemplateFilter| -> Vec<String> {
+            filter.apply(templates.clone()).into_iter().map(|t| t.alias).collect()
+        };
+        // 标签不区分大小写，需全部包含
+        let filter = TemplateFilter {
+            tags: vec!["VUE3".into(), "admin".into()],
+            ..Default::default()
+        };
+        assert!(filter.grouped());
+        assert_eq!(apply(filter), ["vue3-admin", "vue3-ts-admin"]);
+        // 有关键字时按相关度排序，不分组
+        let filter = TemplateFilter {
+            query: Some("admin".into()),
+            tags: vec!["vue3".into()],
+            ..Default::default()
+        };
+        assert!(!filter.grouped());
+        assert_eq!(apply(filter), ["vue3-admin", "vue3-ts-admin"]);
+        assert_eq!(
+            apply(TemplateFilter {
+                query: Some("vue3".into()),
+                default_only: true,
+                ..Default::default()
+            }),
+            ["vue3-vite"]
+        );
+        assert!(apply(TemplateFilter {
+            tags: vec!["ssr".into()],
+            default_only: true,
+            ..Default::default()
+        })
+        .is_empty());
+    }
+
+    #[test]
+    fn sets_fields_by_key() {
+        let fields = editable_fields();
+        assert!(fields.contains(&"ref".to_string()) && fields.contains(&"meta.vue".to_string()));
+        assert!(!fields.contains(&"source".to_string()));
 
-    match find_by_alias(&templates, &alias) {
-        Some(tpl) => {
-            spinner.finish_and_clear();
-            crate::ui::print_template_detail(tpl);
+        let tpl = template("admin");
+        let tpl = set_field(&tpl, "meta.vue", "3").unwrap();
+        assert_eq!(tpl.meta.vue.as_deref(), Some("3"));
+        let tpl = set_field(&tpl, "tags", "[admin, ts]").unwrap();
+        assert_eq!(tpl.tags, ["admin", "ts"]);
+        let tpl = set_field(&tpl, "description", "true").unwrap();
+        assert_eq!(tpl.description, "true");
+        let tpl = set_field(&tpl, "deprecated", "true").unwrap();
+        assert!(tpl.deprecated);
+        assert!(set_field(&tpl, "meta.vue", "").unwrap().meta.vue.is_none());
+        assert!(set_field(&tpl, "deprecated", "maybe").is_err());
+        assert!(set_field(&tpl, "repo", "").is_err());
+        assert!(set_field(&tpl, "colour", "red").is_err());
+    }
+
+    #[test]
+    fn renames_and_updates_references() {
+        let mut old = template("old");
+        old.deprecated = true;
+        old.replaced_by = Some("admin".into());
+        let templates = vec![template("admin"), old, template("other")];
+        let renamed = TemplateInfo {
+            alias: "admin-next".into(),
+            ..templates[0].clone()
+        };
+        let result = apply_edit(templates.clone(), "admin", renamed).unwrap();
+        assert_eq!(result[0].alias, "admin-next");
+        assert_eq!(result[1].replaced_by.as_deref(), Some("admin-next"));
+
+        for alias in ["other", "", "team/admin"] {
+            let renamed = TemplateInfo {
+                alias: alias.into(),
+                ..templates[0].clone()
+            };
+            assert!(apply_edit(templates.clone(), "admin", renamed).is_err());
         }
-        None => {
-            spinner.finish_and_clear();
-            crate::ui::print_warning(&format!("未找到别名为 '{}' 的模板", alias));
+    }
+
+    #[test]
+    fn validates_only_changed_fields_on_edit() {
+        let mut legacy = template("legacy");
+        legacy.version_req = Some("not a range".into());
+        let templates = vec![tagged("admin", &[], true), legacy];
+
+        // 未修改的无效字段不阻止编辑其它字段
+        let edited = TemplateInfo {
+            description: "updated".into(),
+            git_ref: Some(String::new()),
+            tags: vec![" TS ".into(), "ts".into()],
+            is_default: true,
+            ..templates[1].clone()
+        };
+        let result = apply_edit(templates.clone(), "legacy", edited).unwrap();
+        assert_eq!(result[1].description, "updated");
+        assert_eq!(result[1].tags, ["ts"]);
+        assert!(result[1].git_ref.is_none());
+        assert!(!result[0].is_default && result[1].is_default);
+
+        let invalid = [
+            TemplateInfo { version_req: Some("not a range either".into()), ..templates[0].clone() },
+            TemplateInfo { repo: "not a repo".into(), ..templates[0].clone() },
+            TemplateInfo { replaced_by: Some("admin".into()), ..templates[0].clone() },
+        ];
+        for edited in invalid {
+            assert!(apply_edit(templates.clone(), "admin", edited).is_err());
         }
+        assert!(apply_edit(templates.clone(), "missing", template("missing")).is_err());
     }
 }
@@ -0,0 +1,520 @@
+//! 交互式模板管理 (`cvue show -i`)
+//!
+//! 主菜单循环直到选择退出: 浏览并操作单个模板、添加模板、批量删除或修改标签。
+//! 每项操作都调用对应的非交互命令，操作后重新读取模板库；批量操作一次写入，可以整体撤销；
+//! Esc 返回上一级
+
+use crate::commands::list::{
+    self, normalize_tags, FieldAssignment, TemplateFilter, TemplateInfo, TemplateUpdate,
+};
+use crate::error::ActionError;
+use crate::i18n::tr;
+use dialoguer::{theme::ColorfulTheme, Confirm, FuzzySelect, Input, MultiSelect, Select};
+
+/// 单个模板的操作
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Action {
+    Clone,
+    Edit,
+    Rename,
+    SetDefault,
+    Remove,
+    Back,
+}
+
+impl Action {
+    fn label(self) -> String {
+        match self {
+            Action::Clone => tr!("list.action_clone"),
+            Action::Edit => tr!("manage.action_edit"),
+            Action::Rename => tr!("manage.action_rename"),
+            Action::SetDefault => tr!("manage.action_set_default"),
+            Action::Remove => tr!("list.action_remove"),
+            Action::Back => tr!("list.action_back"),
+        }
+    }
+}
+
+/// 运行交互式管理，直到选择退出
+pub fn run(filter: TemplateFilter) -> Result<(), ActionError> {
+    if !crate::ui::interactive() {
+        return Err(ActionError::Other(tr!("manage.not_interactive")));
+    }
+    crate::ui::print_title(&tr!("list.interactive_title"));
+    let menu = [
+        tr!("manage.menu_browse"),
+        tr!("manage.menu_add"),
+        tr!("manage.menu_bulk"),
+        tr!("manage.menu_quit"),
+    ];
+    loop {
+        match select(&tr!("list.select_action"), &menu)? {
+            Some(0) => browse(&filter)?,
+            Some(1) => add()?,
+            Some(2) => bulk(&filter)?,
+            _ => break,
+        }
+    }
+    crate::ui::print_info(&tr!("list.exited"));
+    Ok(())
+}
+
+/// 浏览模板: 输入关键字即时过滤，选中后进入操作菜单
+fn browse(filter: &TemplateFilter) -> Result<(), ActionError> {
+    loop {
+        let templates = visible_templates(filter);
+        if templates.is_empty() {
+            warn_empty(filter);
+            return Ok(());
+        }
+        let mut options: Vec<String> = templates.iter().map(crate::ui::template_option).collect();
+        options.push(tr!("list.action_back"));
+        let selection = FuzzySelect::with_theme(&ColorfulTheme::default())
+            .with_prompt(tr!("manage.select_template"))
+            .items(&options)
+            .default(0)
+            .interact_opt()
+            .map_err(interaction_error)?;
+        match selection {
+            Some(index) if index < templates.len() => manage_template(templates[index].alias.clone())?,
+            _ => return Ok(()),
+        }
+    }
+}
+
+/// 单个模板的操作菜单，模板被删除或选择返回时回到列表
+fn manage_template(mut alias: String) -> Result<(), ActionError> {
+    let mut first = true;
+    loop {
+        let templates = list::load_all_templates();
+        let Some(tpl) = list::find_by_alias(&templates, &alias).cloned() else {
+            return Ok(());
+        };
+        // 上游仓库信息需要访问网络，只在进入时读取一次
+        let upstream = if first { list::upstream_info(&tpl, false) } else { None };
+        first = false;
+        crate::ui::print_template_detail(&tpl, upstream.as_ref());
+
+        // 订阅的模板只读，只能克隆
+        let actions: Vec<Action> = match tpl.source {
+            Some(_) => vec![Action::Clone, Action::Back],
+            None => [
+                Action::Clone,
+                Action::Edit,
+                Action::Rename,
+                Action::SetDefault,
+                Action::Remove,
+                Action::Back,
+            ]
+            .into_iter()
+            .filter(|a| *a != Action::SetDefault || !tpl.is_default)
+            .collect(),
+        };
+        let labels: Vec<String> = actions.iter().map(|a| a.label()).collect();
+        let Some(index) = select(&tr!("list.select_action"), &labels)? else {
+            return Ok(());
+        };
+        match actions[index] {
+            Action::Clone => clone(&tpl)?,
+            Action::Edit => {
+                if let Some(new_alias) = edit(&tpl)? {
+                    alias = new_alias;
+                }
+            }
+            Action::Rename => {
+                let new_alias = input(&tr!("manage.prompt_alias"), &alias)?;
+                if new_alias != alias && report(list::rename_template(alias.clone(), new_alias.clone())) {
+                    alias = new_alias;
+                }
+            }
+            Action::SetDefault => {
+                report(list::update_template(
+                    alias.clone(),
+                    TemplateUpdate {
+                        is_default: Some(true),
+                        ..Default::default()
+                    },
+                ));
+            }
+            Action::Remove => {
+                if crate::ui::confirm(&tr!("list.confirm_remove", alias = alias))? {
+                    if report(list::remove_template(alias.clone())) {
+                        return Ok(());
+                    }
+                } else {
+                    crate::ui::print_info(&tr!("list.remove_cancelled"));
+                }
+            }
+            Action::Back => return Ok(()),
+        }
+    }
+}
+
+/// 克隆模板，目标目录默认根据仓库推导
+fn clone(tpl: &TemplateInfo) -> Result<(), ActionError> {
+    let target = input(
+        &tr!("clone.prompt_target"),
+        &crate::commands::clone::default_target(&tpl.repo),
+    )?;
+    let result = crate::commands::clone::NetworkOptions::from_config().and_then(|network| {
+        crate::commands::clone::run(crate::commands::clone::CloneArgs {
+            template: Some(tpl.alias.clone()),
+            target: Some(target),
+            name: None,
+            token: None,
+            git_ref: None,
+            network,
+        })
+    });
+    if let Err(e) = result {
+        crate::ui::print_error(&tr!("list.clone_failed", error = e));
+    }
+    Ok(())
+}
+
+/// 修改一个字段或在编辑器中编辑整个模板，返回修改后的别名
+fn edit(tpl: &TemplateInfo) -> Result<Option<String>, ActionError> {
+    let fields = list::editable_fields();
+    let mut options = vec![tr!("manage.edit_in_editor")];
+    options.extend(fields.iter().map(|f| format!("{}: {}", f, field_value(tpl, f))));
+    let selection = FuzzySelect::with_theme(&ColorfulTheme::default())
+        .with_prompt(tr!("list.select_field"))
+        .items(&options)
+        .default(0)
+        .interact_opt()
+        .map_err(interaction_error)?;
+    let key = match selection {
+        None => return Ok(None),
+        Some(0) => {
+            report(list::edit_template(tpl.alias.clone()));
+            return Ok(None);
+        }
+        Some(index) => &fields[index - 1],
+    };
+    let value = input(&tr!("manage.prompt_field", field = key), &field_value(tpl, key))?;
+    let updated = report(list::update_template(
+        tpl.alias.clone(),
+        TemplateUpdate {
+            fields: vec![FieldAssignment {
+                key: key.clone(),
+                value: value.clone(),
+            }],
+            ..Default::default()
+        },
+    ));
+    Ok((updated && key == "alias").then_some(value))
+}
+
+/// 添加模板向导，校验与 `cvue add` 相同
+fn add() -> Result<(), ActionError> {
+    let templates = list::load_templates_for_update()?;
+    let theme = ColorfulTheme::default();
+    let repo: String = Input::with_theme(&theme)
+        .with_prompt(tr!("manage.prompt_repo"))
+        .validate_with(|repo: &String| crate::utils::normalize_gh_url(repo).map(|_| ()).map_err(|e| e.to_string()))
+        .interact_text()
+        .map_err(interaction_error)?;
+    let alias: String = Input::with_theme(&theme)
+        .with_prompt(tr!("manage.prompt_alias"))
+        .default(suggest_alias(&repo, &templates))
+        .validate_with(|alias: &String| list::check_new_alias(&templates, alias).map_err(|e| e.to_string()))
+        .interact_text()
+        .map_err(interaction_error)?;
+    let description = input(&tr!("manage.prompt_description"), "")?;
+    let tags = split_tags(&input(&tr!("manage.prompt_tags"), "")?);
+    let version_req: String = Input::with_theme(&theme)
+        .with_prompt(tr!("manage.prompt_version_req"))
+        .allow_empty(true)
+        .validate_with(|req: &String| match req.trim() {
+            "" => Ok(()),
+            req => crate::commands::refs::parse_req(req).map(|_| ()).map_err(|e| e.to_string()),
+        })
+        .interact_text()
+        .map_err(interaction_error)?;
+    let is_default = confirm(&tr!("list.prompt_default"), false)?;
+    let detect = confirm(&tr!("manage.prompt_detect"), false)?;
+
+    report(list::add_template(
+        TemplateInfo {
+            alias,
+            repo,
+            version_req: Some(version_req.trim().to_string()).filter(|req| !req.is_empty()),
+            description,
+            is_default,
+            tags,
+            ..Default::default()
+        },
+        detect,
+    ));
+    Ok(())
+}
+
+/// 批量操作: 勾选本地模板后删除、添加或移除标签
+fn bulk(filter: &TemplateFilter) -> Result<(), ActionError> {
+    let templates: Vec<TemplateInfo> = visible_templates(filter)
+        .into_iter()
+        .filter(|t| t.source.is_none())
+        .collect();
+    if templates.is_empty() {
+        warn_empty(filter);
+        return Ok(());
+    }
+    let options: Vec<String> = templates.iter().map(crate::ui::template_option).collect();
+    let chosen = MultiSelect::with_theme(&ColorfulTheme::default())
+        .with_prompt(tr!("manage.select_bulk"))
+        .items(&options)
+        .interact_opt()
+        .map_err(interaction_error)?
+        .unwrap_or_default();
+    if chosen.is_empty() {
+        crate::ui::print_info(&tr!("common.cancelled"));
+        return Ok(());
+    }
+    let chosen: Vec<&TemplateInfo> = chosen.into_iter().map(|i| &templates[i]).collect();
+    let aliases: Vec<String> = chosen.iter().map(|t| t.alias.clone()).collect();
+
+    let actions = [
+        tr!("manage.bulk_remove"),
+        tr!("manage.bulk_add_tags"),
+        tr!("manage.bulk_remove_tags"),
+        tr!("list.action_back"),
+    ];
+    match select(&tr!("manage.select_bulk_action", count = chosen.len()), &actions)? {
+        Some(0) => {
+            let message = tr!("manage.confirm_bulk_remove", count = chosen.len(), aliases = aliases.join(", "));
+            if crate::ui::confirm(&message)? {
+                report(save_bulk(
+                    |templates| remove_aliases(templates, &aliases),
+                    |count| tr!("manage.bulk_removed", count = count),
+                ));
+            } else {
+                crate::ui::print_info(&tr!("list.remove_cancelled"));
+            }
+        }
+        Some(action @ (1 | 2)) => {
+            let tags = split_tags(&input(&tr!("manage.prompt_tags"), "")?);
+            if tags.is_empty() {
+                crate::ui::print_info(&tr!("list.update_cancelled"));
+                return Ok(());
+            }
+            report(save_bulk(
+                |templates| retag(templates, &aliases, &tags, action == 1),
+                |count| tr!("manage.bulk_tagged", count = count),
+            ));
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// 重新读取本地模板库，修改后只保存一次；`message` 根据修改的模板数量生成成功提示
+fn save_bulk(
+    change: impl FnOnce(&mut Vec<TemplateInfo>) -> usize,
+    message: impl FnOnce(usize) -> String,
+) -> Result<(), ActionError> {
+    let mut templates = list::load_templates_for_update()?;
+    let count = change(&mut templates);
+    if count == 0 {
+        crate::ui::print_info(&tr!("list.edit_unchanged"));
+        return Ok(());
+    }
+    let spinner = crate::ui::create_spinner(&tr!("list.saving"));
+    let result = list::save_templates(&templates);
+    spinner.finish_and_clear();
+    result?;
+    crate::ui::print_success(&message(count));
+    Ok(())
+}
+
+/// 删除指定别名的模板，返回删除的数量
+fn remove_aliases(templates: &mut Vec<TemplateInfo>, aliases: &[String]) -> usize {
+    let len = templates.len();
+    templates.retain(|t| !aliases.contains(&t.alias));
+    len - templates.len()
+}
+
+/// 给指定别名的模板添加 (`add`) 或移除标签，返回标签有变化的模板数量
+fn retag(templates: &mut [TemplateInfo], aliases: &[String], tags: &[String], add: bool) -> usize {
+    let mut count = 0;
+    for tpl in templates.iter_mut().filter(|t| aliases.contains(&t.alias)) {
+        let updated: Vec<String> = if add {
+            normalize_tags(tpl.tags.iter().chain(tags).cloned().collect())
+        } else {
+            tpl.tags.iter().filter(|t| !tags.contains(t)).cloned().collect()
+        };
+        if updated != tpl.tags {
+            tpl.tags = updated;
+            count += 1;
+        }
+    }
+    count
+}
+
+/// 符合 `show` 筛选条件的模板，没有关键字和排序时按标签分组
+fn visible_templates(filter: &TemplateFilter) -> Vec<TemplateInfo> {
+    let templates = filter.apply(list::load_all_templates());
+    if filter.grouped() {
+        list::group_by_tag(templates)
+    } else {
+        templates
+    }
+}
+
+fn warn_empty(filter: &TemplateFilter) {
+    if filter.is_empty() {
+        crate::ui::print_warning(&tr!("common.no_templates"));
+    } else {
+        crate::ui::print_warning(&tr!("list.no_filter_match"));
+    }
+}
+
+/// 字段的当前值，格式与 `update --field` 接受的值一致，未设置时为空
+fn field_value(tpl: &TemplateInfo, key: &str) -> String {
+    let doc = serde_yaml::to_value(tpl).unwrap_or_default();
+    let value = key.split('.').try_fold(&doc, |value, name| value.get(name));
+    value.map(scalar_text).unwrap_or_default()
+}
+
+/// 标量按原文显示，列表显示为 `[a, b]`
+fn scalar_text(value: &serde_yaml::Value) -> String {
+    match value {
+        serde_yaml::Value::String(s) => s.clone(),
+        serde_yaml::Value::Sequence(items) => {
+            let items: Vec<String> = items.iter().map(scalar_text).collect();
+            format!("[{}]", items.join(", "))
+        }
+        value => serde_yaml::to_string(value).unwrap_or_default().trim_end().to_string(),
+    }
+}
+
+/// 根据仓库地址推荐不重复的别名
+fn suggest_alias(repo: &str, templates: &[TemplateInfo]) -> String {
+    let segments: Vec<&str> = repo
+        .trim_end_matches('/')
+        .split(['/', ':'])
+        .filter(|s| !s.is_empty())
+        .collect();
+    let owner = segments.len().checked_sub(2).map_or("", |i| segments[i]);
+    crate::commands::discover::generate_alias(owner, &crate::utils::default_dir_name(repo), templates)
+}
+
+/// 逗号或空格分隔的标签
+fn split_tags(input: &str) -> Vec<String> {
+    normalize_tags(
+        input
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|t| !t.is_empty())
+            .map(str::to_string)
+            .collect(),
+    )
+}
+
+/// 单选，Esc 时返回 `None`
+fn select(prompt: &str, items: &[String]) -> Result<Option<usize>, ActionError> {
+    Select::with_theme(&ColorfulTheme::default())
+        .with_prompt(prompt)
+        .items(items)
+        .default(0)
+        .interact_opt()
+        .map_err(interaction_error)
+}
+
+/// 输入文本，可以为空
+fn input(prompt: &str, initial: &str) -> Result<String, ActionError> {
+    Input::<String>::with_theme(&ColorfulTheme::default())
+        .with_prompt(prompt)
+        .with_initial_text(initial)
+        .allow_empty(true)
+        .interact_text()
+        .map(|s| s.trim().to_string())
+        .map_err(interaction_error)
+}
+
+fn confirm(prompt: &str, default: bool) -> Result<bool, ActionError> {
+    Confirm::with_theme(&ColorfulTheme::default())
+        .with_prompt(prompt)
+        .default(default)
+        .interact()
+        .map_err(interaction_error)
+}
+
+fn interaction_error(e: dialoguer::Error) -> ActionError {
+    ActionError::Other(tr!("common.interaction_error", error = e))
+}
+
+/// 只打印命令的错误，不中断交互，返回是否成功
+fn report(result: Result<(), ActionError>) -> bool {
+    match result {
+        Ok(()) => true,
+        Err(e) => {
+            crate::ui::print_error(&e.to_string());
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_field_values_for_editing() {
+        let tpl = TemplateInfo {
+            alias: "admin".into(),
+            is_default: true,
+            tags: vec!["admin".into(), "ts".into()],
+            meta: list::TemplateMeta {
+                vue: Some("3.4".into()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        assert_eq!(field_value(&tpl, "alias"), "admin");
+        assert_eq!(field_value(&tpl, "is_default"), "true");
+        assert_eq!(field_value(&tpl, "tags"), "[admin, ts]");
+        assert_eq!(field_value(&tpl, "meta.vue"), "3.4");
+        assert_eq!(field_value(&tpl, "ref"), "");
+        // 编辑后的值原样写回
+        for key in ["is_default", "tags", "meta.vue"] {
+            assert_eq!(list::set_field(&tpl, key, &field_value(&tpl, key)).unwrap(), tpl);
+        }
+    }
+
+    #[test]
+    fn splits_tags_and_suggests_aliases() {
+        assert_eq!(split_tags("Admin, ts  vue3,,"), ["admin", "ts", "vue3"]);
+        let templates = vec![TemplateInfo {
+            alias: "starter".into(),
+            ..Default::default()
+        }];
+        assert_eq!(suggest_alias("https://github.com/team/starter.git", &templates), "team-starter");
+        assert_eq!(suggest_alias("team/admin", &templates), "admin");
+        assert_eq!(suggest_alias("https://github.com/team/check.git", &templates), "check-template");
+    }
+
+    #[test]
+    fn applies_bulk_changes_in_one_pass() {
+        let tagged = |alias: &str, tags: &[&str]| TemplateInfo {
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            ..list::test_template(alias)
+        };
+        let templates = vec![tagged("admin", &["ts"]), tagged("list", &[]), tagged("mobile", &["vue3", "ts"])];
+        let chosen = vec!["admin".to_string(), "mobile".into(), "missing".into()];
+
+        let mut removed = templates.clone();
+        assert_eq!(remove_aliases(&mut removed, &chosen), 2);
+        assert_eq!(removed, [templates[1].clone()]);
+
+        let mut added = templates.clone();
+        assert_eq!(retag(&mut added, &chosen, &["ts".into(), "admin".into()], true), 2);
+        assert_eq!(added[0].tags, ["ts", "admin"]);
+        assert!(added[1].tags.is_empty());
+        assert_eq!(added[2].tags, ["vue3", "ts", "admin"]);
+
+        let mut stripped = templates.clone();
+        assert_eq!(retag(&mut stripped, &chosen, &["vue3".into()], false), 1);
+        assert_eq!(stripped[0].tags, ["ts"]);
+        assert_eq!(stripped[2].tags, ["ts"]);
+        assert_eq!(retag(&mut stripped, &chosen, &["vue3".into()], false), 0);
+    }
+}
@@ -8,3 +8,27 @@ pub mod list;
 
 //这个是初始化项目
 pub mod init;
+
+//这个是管理配置
+pub mod config;
+
+//这个是检查模板仓库
+pub mod check;
+
+//这个是查看模板的标签和分支
+pub mod refs;
+
+//这个是从 GitHub 发现新模板
+pub mod discover;
+
+//这个是订阅远程模板库
+pub mod registry;
+
+//这个是模板库的修改历史和撤销
+pub mod history;
+
+//这个是检查和修复模板库
+pub mod doctor;
+
+//这个是交互式管理模板
+pub mod manage;
@@ -0,0 +1,334 @@
+use crate::commands::list::{find_by_alias, load_all_templates, resolve_alias};
+use crate::error::ActionError;
+use crate::git::RemoteRef;
+use crate::i18n::tr;
+use crate::ui::OutputFormat;
+use dialoguer::{theme::ColorfulTheme, Select};
+use semver::{Version, VersionReq};
+use serde::Serialize;
+use std::cmp::Ordering;
+use std::time::Duration;
+
+/// 查看引用命令参数
+pub struct RefsArgs {
+    /// 模板别名或仓库地址
+    pub template: String,
+    /// 只显示满足模板版本约束的标签
+    pub matching: bool,
+}
+
+/// 引用类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RefKind {
+    Tag,
+    Branch,
+}
+
+/// 远程仓库中的一个标签或分支
+#[derive(Debug, Clone, Serialize)]
+pub struct RefEntry {
+    pub name: String,
+    pub kind: RefKind,
+    /// 指向的提交，附注标签取其指向的提交
+    pub commit: String,
+    /// 按 semver 解析出的版本，不是版本号的标签为空
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /// 是否为远程默认分支
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub is_default: bool,
+    /// 是否满足模板的版本约束，模板没有约束时为空
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub matches: Option<bool>,
+}
+
+/// 运行查看引用命令
+pub fn run(args: RefsArgs) -> Result<(), ActionError> {
+    let templates = load_all_templates();
+    let (repo, version_req) = if find_by_alias(&templates, &args.template).is_none()
+        && crate::utils::looks_like_repo(&args.template)
+    {
+        (args.template.clone(), None)
+    } else {
+        let tpl = resolve_alias(&templates, &args.template)?;
+        (tpl.repo.clone(), tpl.version_req.clone())
+    };
+    let req = version_req.as_deref().map(parse_req).transpose()?;
+
+    let spinner = crate::ui::decorated().then(|| crate::ui::create_spinner(&tr!("refs.fetching", repo = repo)));
+    let refs = fetch_refs(&repo, None);
+    if let Some(spinner) = spinner {
+        spinner.finish_and_clear();
+    }
+    let mut refs = classify(&refs?, req.as_ref());
+    if args.matching {
+        refs.retain(|r| r.matches == Some(true));
+    }
+
+    match crate::ui::output_format() {
+        OutputFormat::Table if refs.is_empty() => crate::ui::print_warning(&tr!("refs.empty")),
+        OutputFormat::Table => {
+            crate::ui::print_title(&tr!("refs.title", repo = repo));
+            if let Some(req) = &version_req {
+                crate::ui::print_info(&tr!("refs.constraint", req = req));
+            }
+            crate::ui::print_ref_table(&refs);
+        }
+        OutputFormat::Plain => {
+            for r in &refs {
+                let kind = match r.kind {
+                    RefKind::Tag => "tag",
+                    RefKind::Branch => "branch",
+                };
+                println!("{}\t{}\t{}", r.name, kind, r.commit);
+            }
+        }
+        _ => crate::ui::print_data(&refs)?,
+    }
+    Ok(())
+}
+
+/// 用 `git ls-remote` 列出仓库的引用，地址改写、认证和网络设置与克隆时一致
+pub fn fetch_refs(repo: &str, token: Option<&str>) -> Result<Vec<RemoteRef>, ActionError> {
+    let network = crate::commands::clone::NetworkOptions::from_config()?;
+    let url = crate::commands::clone::remote_url(repo, token)?;
+    crate::git::ls_remote(&url, Duration::from_secs(network.connect_timeout), network.proxy.as_deref())
+}
+
+/// 解析版本约束，例如 `^2.1`、`>=1.0, <2`
+pub fn parse_req(req: &str) -> Result<VersionReq, ActionError> {
+    VersionReq::parse(req).map_err(|e| ActionError::Other(tr!("refs.invalid_req", req = req, error = e)))
+}
+
+/// 把标签名解析为版本号
+///
+/// 去掉 `v` 前缀和 `pkg@` 前缀，缺少的次版本号和修订号补 0，例如 `v2.1` → `2.1.0`
+pub fn parse_version(tag: &str) -> Option<Version> {
+    let tag = tag.rsplit('@').next().unwrap_or(tag);
+    let tag = tag.strip_prefix(['v', 'V']).unwrap_or(tag);
+    Version::parse(tag).ok().or_else(|| {
+        let parts: Vec<&str> = tag.split('.').collect();
+        if parts.len() < 3 && parts.iter().all(|p| !p.is_empty() && p.chars().all(|c| c.is_ascii_digit())) {
+            let mut parts = parts;
+            parts.resize(3, "0");
+            Version::parse(&parts.join(".")).ok()
+        } else {
+            None
+        }
+    })
+}
+
+/// 把 `ls-remote` 结果整理为标签和分支列表
+///
+/// 标签按版本从新到旧排列，不是版本号的标签按名称排在后面；
+/// 分支中默认分支在前，其余按名称排列
+pub fn classify(refs: &[RemoteRef], req: Option<&VersionReq>) -> Vec<RefEntry> {
+    let default_branch = default_branch(refs);
+    let mut tags: Vec<(Option<Version>, RefEntry)> = Vec::new();
+    let mut branches = Vec::new();
+    for r in refs {
+        if let Some(name) = r.name.strip_prefix("refs/tags/") {
+            if name.ends_with("^{}") {
+                continue;
+            }
+            // 附注标签取 `^{}` 指向的提交
+            let peeled = format!("{}^{{}}", r.name);
+            let commit = refs.iter().find(|p| p.name == peeled).unwrap_or(r).sha.clone();
+            let version = parse_version(name);
+            tags.push((
+                version.clone(),
+                RefEntry {
+                    name: name.to_string(),
+                    kind: RefKind::Tag,
+                    commit,
+                    version: version.as_ref().map(Version::to_string),
+                    is_default: false,
+                    matches: req.map(|req| version.as_ref().is_some_and(|v| req.matches(v))),
+                },
+            ));
+        } else if let Some(name) = r.name.strip_prefix("refs/heads/") {
+            branches.push(RefEntry {
+                name: name.to_string(),
+                kind: RefKind::Branch,
+                commit: r.sha.clone(),
+                version: None,
+                is_default: Some(name) == default_branch,
+                matches: None,
+            });
+        }
+    }
+    tags.sort_by(|(va, a), (vb, b)| match (va, vb) {
+        (Some(va), Some(vb)) => vb.cmp(va),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => b.name.cmp(&a.name),
+    });
+    branches.sort_by(|a, b| b.is_default.cmp(&a.is_default).then_with(|| a.name.cmp(&b.name)));
+    tags.into_iter().map(|(_, entry)| entry).chain(branches).collect()
+}
+
+/// 远程默认分支: `HEAD` 指向的分支
+///
+/// 服务器没有返回符号引用时，只有唯一一个分支与 `HEAD` 指向同一提交才认为是默认分支
+fn default_branch(refs: &[RemoteRef]) -> Option<&str> {
+    let head = refs.iter().find(|r| r.name == "HEAD")?;
+    if let Some(target) = &head.target {
+        return target.strip_prefix("refs/heads/");
+    }
+    let mut same = refs
+        .iter()
+        .filter(|r| r.sha == head.sha)
+        .filter_map(|r| r.name.strip_prefix("refs/heads/"));
+    match (same.next(), same.next()) {
+        (Some(name), None) => Some(name),
+        _ => None,
+    }
+}
+
+/// 满足版本约束的最新标签
+pub fn latest_matching<'a>(refs: &'a [RefEntry], req: &VersionReq) -> Option<&'a RefEntry> {
+    refs.iter().find(|r| {
+        r.kind == RefKind::Tag
+            && r.version
+                .as_deref()
+                .and_then(|v| Version::parse(v).ok())
+                .is_some_and(|v| req.matches(&v))
+    })
+}
+
+/// 按版本约束选择要检出的标签，没有满足约束的标签时报错
+///
+/// `sources` 为克隆源 (展示地址, 实际地址)，与克隆时一样依次尝试，读取引用失败时换下一个源
+pub fn resolve_constraint(
+    sources: &[(String, String)],
+    req: &str,
+    network: &crate::commands::clone::NetworkOptions,
+) -> Result<String, ActionError> {
+    let parsed = parse_req(req)?;
+    let mut last_err = None;
+    for (display, url) in sources {
+        match crate::git::ls_remote(url, Duration::from_secs(network.connect_timeout), network.proxy.as_deref()) {
+            Ok(remote) => {
+                return latest_matching(&classify(&remote, Some(&parsed)), &parsed)
+                    .map(|r| r.name.clone())
+                    .ok_or_else(|| ActionError::Other(tr!("refs.no_match", req = req, repo = display)));
+            }
+            Err(e) => {
+                crate::ui::print_warning(&tr!("refs.source_failed", source = display, error = e));
+                last_err = Some(e);
+            }
+        }
+    }
+    Err(last_err.unwrap_or_else(|| ActionError::Other(tr!("clone.no_sources"))))
+}
+
+/// 交互式选择要检出的标签或分支，返回 `None` 表示使用远程默认分支
+///
+/// 有版本约束时默认选中满足约束的最新标签；读取引用失败时给出警告并使用默认分支
+pub fn pick_ref(repo: &str, version_req: Option<&str>, token: Option<&str>) -> Result<Option<String>, ActionError> {
+    let req = version_req.map(parse_req).transpose()?;
+    let spinner = crate::ui::create_spinner(&tr!("refs.fetching", repo = repo));
+    let fetched = fetch_refs(repo, token);
+    spinner.finish_and_clear();
+    let refs = match fetched {
+        Ok(refs) => classify(&refs, req.as_ref()),
+        Err(e) => {
+            crate::ui::print_warning(&tr!("refs.fetch_failed", error = e));
+            return Ok(None);
+        }
+    };
+    if refs.is_empty() {
+        return Ok(None);
+    }
+
+    let default_branch = refs.iter().find(|r| r.is_default).map(|r| r.name.clone());
+    let mut options = vec![tr!("refs.option_default", branch = default_branch.as_deref().unwrap_or("HEAD"))];
+    options.extend(refs.iter().map(crate::ui::ref_option));
+    let default = req
+        .as_ref()
+        .and_then(|req| latest_matching(&refs, req))
+        .and_then(|found| refs.iter().position(|r| r.name == found.name && r.kind == found.kind))
+        .map_or(0, |i| i + 1);
+    let selection = Select::with_theme(&ColorfulTheme::default())
+        .with_prompt(tr!("refs.select"))
+        .items(&options)
+        .default(default)
+        .max_length(15)
+        .interact()
+        .map_err(|e| ActionError::Other(tr!("common.interaction_error", error = e)))?;
+    // 选择默认分支时也明确检出该分支，避免再按版本约束选择标签
+    Ok(match selection.checked_sub(1) {
+        Some(i) => Some(refs[i].name.clone()),
+        None => default_branch,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn remote(name: &str, sha: &str) -> RemoteRef {
+        RemoteRef {
+            sha: sha.to_string(),
+            name: name.to_string(),
+            target: None,
+        }
+    }
+
+    fn branches(refs: &[RemoteRef]) -> Vec<(String, bool)> {
+        classify(refs, None).into_iter().map(|r| (r.name, r.is_default)).collect()
+    }
+
+    #[test]
+    fn marks_only_the_symref_target_as_default() {
+        // dev 与 main 指向同一提交，只有 HEAD 指向的 main 是默认分支
+        let refs = crate::git::parse_ls_remote(
+            "ref: refs/heads/main\tHEAD\naaa\tHEAD\naaa\trefs/heads/dev\naaa\trefs/heads/main\nbbb\trefs/heads/feature\n",
+        );
+        assert_eq!(refs[0].target.as_deref(), Some("refs/heads/main"));
+        assert_eq!(
+            branches(&refs),
+            [("main".into(), true), ("dev".into(), false), ("feature".into(), false)]
+        );
+    }
+
+    #[test]
+    fn guesses_default_branch_without_symref_only_when_unambiguous() {
+        let refs = vec![remote("HEAD", "aaa"), remote("refs/heads/dev", "aaa"), remote("refs/heads/main", "aaa")];
+        assert!(branches(&refs).iter().all(|(_, is_default)| !is_default));
+        let refs = vec![remote("HEAD", "aaa"), remote("refs/heads/dev", "bbb"), remote("refs/heads/main", "aaa")];
+        assert_eq!(branches(&refs), [("main".into(), true), ("dev".into(), false)]);
+    }
+
+    #[test]
+    fn parses_tag_versions() {
+        assert_eq!(parse_version("v2.1.3"), Some(Version::new(2, 1, 3)));
+        assert_eq!(parse_version("create-vue@3.9.0"), Some(Version::new(3, 9, 0)));
+        assert_eq!(parse_version("v2.1"), Some(Version::new(2, 1, 0)));
+        assert_eq!(parse_version("latest"), None);
+    }
+
+    #[test]
+    fn sorts_tags_by_version_and_applies_constraint() {
+        let refs = vec![
+            remote("HEAD", "aaa"),
+            remote("refs/heads/dev", "bbb"),
+            remote("refs/heads/main", "aaa"),
+            remote("refs/tags/v1.9.0", "c19"),
+            remote("refs/tags/v2.10.0", "t210"),
+            remote("refs/tags/v2.10.0^{}", "c210"),
+            remote("refs/tags/v2.2.0", "c22"),
+            remote("refs/tags/nightly", "cn"),
+            remote("refs/tags/v3.0.0", "c30"),
+        ];
+        let req = parse_req("^2.1").unwrap();
+        let entries = classify(&refs, Some(&req));
+        let names: Vec<_> = entries.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, ["v3.0.0", "v2.10.0", "v2.2.0", "v1.9.0", "nightly", "main", "dev"]);
+        assert_eq!(entries[1].commit, "c210");
+        assert!(entries[5].is_default);
+        assert_eq!(latest_matching(&entries, &req).map(|r| r.name.as_str()), Some("v2.10.0"));
+        assert!(latest_matching(&entries, &parse_req("^4").unwrap()).is_none());
+    }
+}
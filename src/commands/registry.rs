@@ -0,0 +1,729 @@
+//! 订阅共享的远程模板库
+//!
+//! 订阅记录在配置文件的 `subscriptions` 中，远程文档缓存在用户缓存目录下的
+//! `cvue/registries/<名称>.yaml`。订阅的模板以 `<名称>/<别名>` 的形式与本地模板
+//! 合并显示，只读，不会写入本地模板库
+//!
+//! 本地模板库可以导出为 YAML、JSON 或 TOML 文档，导入时先预览新增、覆盖和冲突的别名
+
+use crate::commands::list::{find_by_alias, load_templates, load_templates_for_update, save_templates, TemplateFilter, TemplateInfo};
+use crate::config::{self, Subscription};
+use crate::error::ActionError;
+use crate::i18n::tr;
+use crate::ui::OutputFormat;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+use toml::Value;
+use url::Url;
+
+/// 模板库子命令
+pub enum RegistryAction {
+    Subscribe {
+        source: String,
+        name: Option<String>,
+        path: Option<String>,
+    },
+    Unsubscribe { name: String },
+    Sync { names: Vec<String> },
+    List,
+    Log { limit: usize },
+    Doctor(crate::commands::doctor::DoctorArgs),
+    Export(ExportArgs),
+    Import(ImportArgs),
+}
+
+/// 模板库文档格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum DocumentFormat {
+    Yaml,
+    Json,
+    Toml,
+}
+
+/// 导出命令参数
+pub struct ExportArgs {
+    pub format: DocumentFormat,
+    /// 只导出符合条件的模板，未设置条件时保持原有顺序
+    pub filter: TemplateFilter,
+}
+
+/// 导入时别名已存在且内容不同的处理方式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum ConflictStrategy {
+    /// 保留本地模板
+    #[default]
+    Skip,
+    /// 用导入的模板覆盖
+    Overwrite,
+    /// 导入的模板改用新别名
+    Rename,
+}
+
+/// 导入命令参数
+pub struct ImportArgs {
+    /// 文件路径，`-` 表示标准输入
+    pub file: String,
+    /// 文档格式，为空时按扩展名推断
+    pub format: Option<DocumentFormat>,
+    pub strategy: ConflictStrategy,
+    /// 不询问直接导入
+    pub yes: bool,
+    /// 只预览，不修改模板库
+    pub dry_run: bool,
+}
+
+/// 单个模板的导入结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportAction {
+    Add,
+    Overwrite,
+    Rename,
+    Skip,
+    Unchanged,
+}
+
+impl ImportAction {
+    /// 与结构化输出一致的名称
+    pub fn name(self) -> &'static str {
+        match self {
+            ImportAction::Add => "add",
+            ImportAction::Overwrite => "overwrite",
+            ImportAction::Rename => "rename",
+            ImportAction::Skip => "skip",
+            ImportAction::Unchanged => "unchanged",
+        }
+    }
+}
+
+/// 导入预览中的一项
+#[derive(Debug, Serialize)]
+pub struct ImportEntry {
+    pub alias: String,
+    pub action: ImportAction,
+    /// 重命名后的别名
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub renamed_to: Option<String>,
+    #[serde(skip)]
+    pub template: TemplateInfo,
+}
+
+/// TOML 文档的顶层必须是表，模板放在 `[[templates]]` 中
+#[derive(Serialize, Deserialize)]
+struct TomlDocument {
+    templates: Vec<TemplateInfo>,
+}
+
+/// 订阅状态，用于 `registry list` 的结构化输出
+#[derive(Debug, Serialize)]
+pub struct SubscriptionStatus {
+    pub name: String,
+    pub url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    /// 缓存中的模板数量，尚未同步时为空
+    pub templates: Option<usize>,
+    /// 距上次同步的秒数
+    pub synced_secs_ago: Option<u64>,
+}
+
+/// 运行模板库子命令
+pub fn run(action: RegistryAction) -> Result<(), ActionError> {
+    match action {
+        RegistryAction::Subscribe { source, name, path } => subscribe(source, name, path),
+        RegistryAction::Unsubscribe { name } => unsubscribe(&name),
+        RegistryAction::Sync { names } => sync(&names),
+        RegistryAction::List => list(),
+        RegistryAction::Log { limit } => crate::commands::history::log(limit),
+        RegistryAction::Doctor(args) => crate::commands::doctor::run(args),
+        RegistryAction::Export(args) => export(args),
+        RegistryAction::Import(args) => import(args),
+    }
+}
+
+/// 订阅远程模板库，立即拉取一次
+fn subscribe(source: String, name: Option<String>, path: Option<String>) -> Result<(), ActionError> {
+    let name = name.unwrap_or_else(|| default_name(&source));
+    validate_name(&name)?;
+    if config::get().subscriptions.iter().any(|s| s.name == name) {
+        return Err(ActionError::Other(tr!("registry.exists", name = name)));
+    }
+    let subscription = Subscription {
+        name: name.clone(),
+        url: source,
+        path,
+    };
+    let count = pull(&subscription)?;
+
+    let mut table = config::load_table()?;
+    let list = subscriptions_array(&mut table);
+    let mut entry = toml::Table::new();
+    entry.insert("name".into(), Value::String(subscription.name.clone()));
+    entry.insert("url".into(), Value::String(subscription.url.clone()));
+    if let Some(path) = &subscription.path {
+        entry.insert("path".into(), Value::String(path.clone()));
+    }
+    list.push(Value::Table(entry));
+    config::save_table(&table)?;
+    crate::ui::print_success(&tr!("registry.subscribed", name = name, count = count));
+    Ok(())
+}
+
+/// 取消订阅并删除缓存
+fn unsubscribe(name: &str) -> Result<(), ActionError> {
+    let mut table = config::load_table()?;
+    let list = subscriptions_array(&mut table);
+    let old_len = list.len();
+    list.retain(|s| s.get("name").and_then(Value::as_str) != Some(name));
+    if list.len() == old_len {
+        return Err(ActionError::Other(tr!("registry.not_found", name = name)));
+    }
+    if list.is_empty() {
+        table.remove("subscriptions");
+    }
+    config::save_table(&table)?;
+    if let Some(path) = cache_path(name) {
+        let _ = fs::remove_file(path);
+    }
+    crate::ui::print_success(&tr!("registry.unsubscribed", name = name));
+    Ok(())
+}
+
+/// 重新拉取订阅的模板库，单个失败不影响其它
+fn sync(names: &[String]) -> Result<(), ActionError> {
+    let subscriptions = &config::get().subscriptions;
+    for name in names {
+        if !subscriptions.iter().any(|s| &s.name == name) {
+            return Err(ActionError::Other(tr!("registry.not_found", name = name)));
+        }
+    }
+    let selected: Vec<&Subscription> = subscriptions
+        .iter()
+        .filter(|s| names.is_empty() || names.contains(&s.name))
+        .collect();
+    if selected.is_empty() {
+        crate::ui::print_info(&tr!("registry.none"));
+        return Ok(());
+    }
+    let mut failed = 0;
+    for subscription in selected {
+        match pull(subscription) {
+            Ok(count) => crate::ui::print_success(&tr!("registry.synced", name = subscription.name, count = count)),
+            Err(e) => {
+                failed += 1;
+                crate::ui::print_error(&tr!("registry.sync_failed", name = subscription.name, error = e));
+            }
+        }
+    }
+    if failed > 0 {
+        return Err(ActionError::Other(tr!("registry.sync_failed_count", count = failed)));
+    }
+    Ok(())
+}
+
+/// 列出订阅及缓存状态
+fn list() -> Result<(), ActionError> {
+    let statuses: Vec<SubscriptionStatus> = config::get()
+        .subscriptions
+        .iter()
+        .map(|s| {
+            let cache = cache_path(&s.name);
+            SubscriptionStatus {
+                name: s.name.clone(),
+                url: s.url.clone(),
+                path: s.path.clone(),
+                templates: read_cache(&s.name).map(|t| t.len()),
+                synced_secs_ago: cache
+                    .and_then(|p| fs::metadata(p).ok()?.modified().ok())
+                    .and_then(|t| SystemTime::now().duration_since(t).ok())
+                    .map(|d| d.as_secs()),
+            }
+        })
+        .collect();
+    match crate::ui::output_format() {
+        OutputFormat::Table if statuses.is_empty() => crate::ui::print_info(&tr!("registry.none")),
+        OutputFormat::Table => {
+            crate::ui::print_title(&tr!("registry.title"));
+            crate::ui::print_subscription_table(&statuses);
+        }
+        OutputFormat::Plain => {
+            for s in &statuses {
+                println!("{}\t{}\t{}", s.name, s.url, s.templates.map(|n| n.to_string()).unwrap_or_default());
+            }
+        }
+        _ => crate::ui::print_data(&statuses)?,
+    }
+    Ok(())
+}
+
+/// 把本地模板库导出到标准输出
+fn export(args: ExportArgs) -> Result<(), ActionError> {
+    let templates = load_templates();
+    let templates = if args.filter.is_empty() && args.filter.sort.is_none() {
+        templates
+    } else {
+        args.filter.apply(templates)
+    };
+    print!("{}", to_document(&templates, args.format)?);
+    crate::ui::print_info(&tr!("registry.exported", count = templates.len()));
+    Ok(())
+}
+
+/// 导入模板文档，预览后确认写入
+fn import(args: ImportArgs) -> Result<(), ActionError> {
+    let content = if args.file == "-" {
+        let mut content = String::new();
+        std::io::stdin().read_to_string(&mut content)?;
+        content
+    } else {
+        fs::read_to_string(&args.file)
+            .map_err(|e| ActionError::Other(tr!("registry.file_missing", path = args.file, error = e)))?
+    };
+    let format = args.format.or_else(|| format_from_path(&args.file));
+    let incoming = from_document(&content, format)?;
+    validate_imported(&incoming)?;
+
+    let templates = load_templates_for_update()?;
+    let plan = plan_import(&templates, incoming, args.strategy);
+    match crate::ui::output_format() {
+        OutputFormat::Table => {
+            crate::ui::print_title(&tr!("registry.import_preview"));
+            crate::ui::print_import_table(&plan);
+        }
+        OutputFormat::Plain => {
+            for entry in &plan {
+                println!("{}\t{}\t{}", entry.alias, entry.action.name(), entry.renamed_to.as_deref().unwrap_or_default());
+            }
+        }
+        _ => crate::ui::print_data(&plan)?,
+    }
+
+    let changes = plan
+        .iter()
+        .filter(|e| !matches!(e.action, ImportAction::Skip | ImportAction::Unchanged))
+        .count();
+    if changes == 0 {
+        crate::ui::print_info(&tr!("registry.import_nothing"));
+        return Ok(());
+    }
+    if args.dry_run {
+        crate::ui::print_info(&tr!("registry.dry_run", count = changes));
+        return Ok(());
+    }
+    if !args.yes {
+        if !crate::ui::interactive() {
+            return Err(ActionError::Other(tr!("registry.import_needs_yes")));
+        }
+        if !crate::ui::confirm(&tr!("registry.confirm_import", count = changes))? {
+            crate::ui::print_info(&tr!("common.cancelled"));
+            return Ok(());
+        }
+    }
+
+    let spinner = crate::ui::create_spinner(&tr!("list.saving"));
+    let result = save_templates(&apply_import(templates, plan));
+    spinner.finish_and_clear();
+    result?;
+    crate::ui::print_success(&tr!("registry.imported", count = changes));
+    Ok(())
+}
+
+/// 把模板序列化为指定格式的文档
+pub fn to_document(templates: &[TemplateInfo], format: DocumentFormat) -> Result<String, ActionError> {
+    Ok(match format {
+        DocumentFormat::Yaml => serde_yaml::to_string(templates)?,
+        DocumentFormat::Json => serde_json::to_string_pretty(templates)? + "\n",
+        DocumentFormat::Toml => toml::to_string_pretty(&TomlDocument {
+            templates: templates.to_vec(),
+        })?,
+    })
+}
+
+/// 解析模板文档，未指定格式时依次尝试 YAML (兼容 JSON) 和 TOML
+pub fn from_document(content: &str, format: Option<DocumentFormat>) -> Result<Vec<TemplateInfo>, ActionError> {
+    match format {
+        Some(DocumentFormat::Yaml) => Ok(serde_yaml::from_str(content)?),
+        Some(DocumentFormat::Json) => Ok(serde_json::from_str(content)?),
+        Some(DocumentFormat::Toml) => Ok(toml::from_str::<TomlDocument>(content)?.templates),
+        None => serde_yaml::from_str(content).or_else(|e| {
+            toml::from_str::<TomlDocument>(content)
+                .map(|doc| doc.templates)
+                .map_err(|_| ActionError::from(e))
+        }),
+    }
+}
+
+/// 按扩展名推断文档格式
+fn format_from_path(path: &str) -> Option<DocumentFormat> {
+    match Path::new(path).extension()?.to_str()?.to_ascii_lowercase().as_str() {
+        "yaml" | "yml" => Some(DocumentFormat::Yaml),
+        "json" => Some(DocumentFormat::Json),
+        "toml" => Some(DocumentFormat::Toml),
+        _ => None,
+    }
+}
+
+/// 检查导入的别名、仓库地址和版本约束
+fn validate_imported(templates: &[TemplateInfo]) -> Result<(), ActionError> {
+    let reserved = crate::cli::reserved_names();
+    for (i, tpl) in templates.iter().enumerate() {
+        if tpl.alias.is_empty() || tpl.alias.contains('/') {
+            return Err(ActionError::Other(tr!("registry.invalid_alias", alias = tpl.alias)));
+        }
+        if reserved.contains(&tpl.alias) {
+            return Err(ActionError::Other(tr!("list.alias_reserved", alias = tpl.alias)));
+        }
+        if templates[..i].iter().any(|t| t.alias == tpl.alias) {
+            return Err(ActionError::Other(tr!("registry.duplicate_alias", alias = tpl.alias)));
+        }
+        crate::utils::normalize_gh_url(&tpl.repo)?;
+        if let Some(req) = &tpl.version_req {
+            crate::commands::refs::parse_req(req)?;
+        }
+    }
+    Ok(())
+}
+
+/// 对比本地模板库，决定每个导入模板的处理方式
+///
+/// 本地已有默认模板时，导入的模板不会取代它
+pub fn plan_import(local: &[TemplateInfo], incoming: Vec<TemplateInfo>, strategy: ConflictStrategy) -> Vec<ImportEntry> {
+    let mut has_default = local.iter().any(|t| t.is_default);
+    let mut taken: Vec<String> = local.iter().chain(&incoming).map(|t| t.alias.clone()).collect();
+    let mut plan = Vec::new();
+    for mut tpl in incoming {
+        let alias = tpl.alias.clone();
+        let existing = find_by_alias(local, &alias);
+        tpl.is_default = match existing {
+            Some(existing) if strategy == ConflictStrategy::Overwrite => existing.is_default,
+            _ => tpl.is_default && !has_default,
+        };
+        let (action, renamed_to) = match existing {
+            None => (ImportAction::Add, None),
+            Some(existing) if *existing == tpl => (ImportAction::Unchanged, None),
+            Some(_) => match strategy {
+                ConflictStrategy::Skip => (ImportAction::Skip, None),
+                ConflictStrategy::Overwrite => (ImportAction::Overwrite, None),
+                ConflictStrategy::Rename => {
+                    let renamed = (2..)
+                        .map(|n| format!("{}-{}", alias, n))
+                        .find(|a| !taken.contains(a))
+                        .unwrap_or_default();
+                    taken.push(renamed.clone());
+                    tpl.alias = renamed.clone();
+                    (ImportAction::Rename, Some(renamed))
+                }
+            },
+        };
+        // 跳过的模板不占用默认模板
+        if matches!(action, ImportAction::Add | ImportAction::Rename | ImportAction::Overwrite) {
+            has_default |= tpl.is_default;
+        }
+        plan.push(ImportEntry {
+            alias,
+            action,
+            renamed_to,
+            template: tpl,
+        });
+    }
+    plan
+}
+
+/// 按导入计划修改模板库，覆盖的模板保持原有位置，新增的追加到末尾
+pub fn apply_import(mut templates: Vec<TemplateInfo>, plan: Vec<ImportEntry>) -> Vec<TemplateInfo> {
+    for entry in plan {
+        match entry.action {
+            ImportAction::Add | ImportAction::Rename => templates.push(entry.template),
+            ImportAction::Overwrite => {
+                if let Some(slot) = templates.iter_mut().find(|t| t.alias == entry.alias) {
+                    *slot = entry.template;
+                }
+            }
+            ImportAction::Skip | ImportAction::Unchanged => {}
+        }
+    }
+    templates
+}
+
+/// 所有订阅的模板，别名带命名空间前缀。尚未同步或缓存损坏的订阅会被忽略
+pub fn subscribed_templates() -> Vec<TemplateInfo> {
+    config::get()
+        .subscriptions
+        .iter()
+        .filter_map(|s| Some(namespaced(&s.name, read_cache(&s.name)?)))
+        .flatten()
+        .collect()
+}
+
+/// 给订阅的模板加上命名空间，默认模板只能在本地设置
+pub fn namespaced(name: &str, templates: Vec<TemplateInfo>) -> Vec<TemplateInfo> {
+    templates
+        .into_iter()
+        .map(|tpl| TemplateInfo {
+            alias: format!("{}/{}", name, tpl.alias),
+            replaced_by: tpl.replaced_by.map(|r| format!("{}/{}", name, r)),
+            is_default: false,
+            source: Some(name.to_string()),
+            ..tpl
+        })
+        .collect()
+}
+
+/// 拉取远程文档并写入缓存，返回模板数量
+fn pull(subscription: &Subscription) -> Result<usize, ActionError> {
+    let spinner = crate::ui::create_spinner(&tr!("registry.fetching", name = subscription.name));
+    let content = fetch(subscription);
+    spinner.finish_and_clear();
+    let content = content?;
+    let templates: Vec<TemplateInfo> = serde_yaml::from_str(&content)
+        .map_err(|e| ActionError::Other(tr!("registry.invalid_document", url = subscription.url, error = e)))?;
+    if let Some(bad) = templates.iter().find(|t| t.alias.is_empty() || t.alias.contains('/')) {
+        return Err(ActionError::Other(tr!("registry.invalid_alias", alias = bad.alias)));
+    }
+    let path = cache_path(&subscription.name).ok_or_else(|| ActionError::Other(tr!("registry.no_cache_dir")))?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&path, serde_yaml::to_string(&templates)?)?;
+    Ok(templates.len())
+}
+
+/// 读取远程文档内容: 本地文件、HTTP(S) 地址或 git 仓库中的文件
+fn fetch(subscription: &Subscription) -> Result<String, ActionError> {
+    let source = subscription.url.as_str();
+    let local = source.strip_prefix("file://").unwrap_or(source);
+    if Path::new(local).is_file() {
+        return Ok(fs::read_to_string(local)?);
+    }
+    if is_git_source(source) {
+        return fetch_from_git(subscription);
+    }
+    crate::ui::debug(&tr!("github.fetching", url = source));
+    let response = crate::github::http_client()?.get(source).send()?.error_for_status()?;
+    Ok(response.text()?)
+}
+
+/// 浅克隆仓库并读取其中的模板库文件 (默认 `templates.yaml`)
+fn fetch_from_git(subscription: &Subscription) -> Result<String, ActionError> {
+    let root = crate::utils::private_temp_dir("cvue-registry")?;
+    let dir = root.join(&subscription.name);
+    let network = crate::commands::clone::NetworkOptions::from_config()?;
+    let opts = crate::git::CloneOptions {
+        connect_timeout: Duration::from_secs(network.connect_timeout),
+        idle_timeout: Duration::from_secs(network.idle_timeout),
+        proxy: network.proxy,
+        branch: None,
+    };
+    let url = crate::commands::clone::remote_url(&subscription.url, None)?;
+    let result = crate::git::clone(&url, &dir, &opts).and_then(|_| {
+        let file = dir.join(subscription.path.as_deref().unwrap_or("templates.yaml"));
+        fs::read_to_string(&file)
+            .map_err(|e| ActionError::Other(tr!("registry.file_missing", path = file.display(), error = e)))
+    });
+    let _ = fs::remove_dir_all(&root);
+    result
+}
+
+/// 来源是否为 git 仓库: `owner/repo`、`.git` 结尾、本地目录或 GitHub 仓库地址
+pub fn is_git_source(source: &str) -> bool {
+    let local = source.strip_prefix("file://").unwrap_or(source);
+    if source.ends_with(".git") || Path::new(local).is_dir() {
+        return true;
+    }
+    match Url::parse(source) {
+        Ok(url) => {
+            url.host_str() == Some("github.com")
+                && url.path_segments().is_some_and(|s| s.filter(|s| !s.is_empty()).count() == 2)
+        }
+        Err(_) => Regex::new(r"^[\w.-]+/[\w.-]+$").is_ok_and(|re| re.is_match(source)),
+    }
+}
+
+/// 根据来源推导订阅名称，例如 `https://example.com/team/templates.yaml` → `team`
+pub fn default_name(source: &str) -> String {
+    let trimmed = source.trim_end_matches('/');
+    let mut segments: Vec<&str> = trimmed
+        .split(['/', ':'])
+        .filter(|s| !s.is_empty())
+        .map(|s| s.trim_end_matches(".git"))
+        .collect();
+    // 文件名本身没有区分度时取上一级目录
+    if segments
+        .last()
+        .is_some_and(|s| s.ends_with(".yaml") || s.ends_with(".yml") || s.ends_with(".json"))
+    {
+        segments.pop();
+    }
+    let name = segments.last().copied().unwrap_or("registry");
+    crate::utils::suggest_package_name(name)
+}
+
+/// 订阅名称用作别名前缀，只允许小写字母、数字、`-`、`_` 和 `.`
+fn validate_name(name: &str) -> Result<(), ActionError> {
+    if Regex::new(r"^[a-z0-9][a-z0-9._-]*$")?.is_match(name) {
+        Ok(())
+    } else {
+        Err(ActionError::Other(tr!("registry.invalid_name", name = name)))
+    }
+}
+
+/// 订阅的缓存文件
+pub fn cache_path(name: &str) -> Option<PathBuf> {
+    Some(dirs::cache_dir()?.join("cvue").join("registries").join(format!("{}.yaml", name)))
+}
+
+/// 读取缓存的模板库
+fn read_cache(name: &str) -> Option<Vec<TemplateInfo>> {
+    serde_yaml::from_str(&fs::read_to_string(cache_path(name)?).ok()?).ok()
+}
+
+/// 取得配置表中的订阅数组，不存在时创建
+fn subscriptions_array(table: &mut toml::Table) -> &mut Vec<Value> {
+    let entry = table
+        .entry("subscriptions")
+        .or_insert_with(|| Value::Array(Vec::new()));
+    if !entry.is_array() {
+        *entry = Value::Array(Vec::new());
+    }
+    entry.as_array_mut().expect("subscriptions 已确保为数组")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::list::test_template as template;
+
+    #[test]
+    fn detects_source_kind_and_name() {
+        assert!(is_git_source("platform/templates"));
+        assert!(is_git_source("https://github.com/platform/templates"));
+        assert!(is_git_source("https://git.example.com/platform/templates.git"));
+        assert!(!is_git_source("https://example.com/team/templates.yaml"));
+        assert_eq!(default_name("https://example.com/team/templates.yaml"), "team");
+        assert_eq!(default_name("git@github.com:platform/Vue-Templates.git"), "vue-templates");
+    }
+
+    #[test]
+    fn derives_and_validates_subscription_names() {
+        assert_eq!(default_name("https://example.com/team/"), "team");
+        assert_eq!(default_name("./shared/registry.yml"), "shared");
+        assert_eq!(default_name("templates.json"), "registry");
+        assert!(validate_name("team-2.x").is_ok());
+        for name in ["", "Team", "-team", "team/a", "../team"] {
+            assert!(validate_name(name).is_err(), "{name}");
+        }
+    }
+
+    #[test]
+    fn namespaces_subscribed_templates() {
+        let templates = vec![TemplateInfo {
+            alias: "admin-starter".into(),
+            is_default: true,
+            replaced_by: Some("admin-next".into()),
+            ..Default::default()
+        }];
+        let merged = namespaced("team", templates);
+        assert_eq!(merged[0].alias, "team/admin-starter");
+        assert_eq!(merged[0].replaced_by.as_deref(), Some("team/admin-next"));
+        assert_eq!(merged[0].source.as_deref(), Some("team"));
+        assert!(!merged[0].is_default);
+    }
+
+    #[test]
+    fn round_trips_every_format() {
+        let mut templates = vec![template("vue3-vite"), template("nuxt3")];
+        templates[0].git_ref = Some("v3.9.0".into());
+        templates[0].version_req = Some("^3".into());
+        templates[1].deprecated = true;
+        templates[1].replaced_by = Some("vue3-vite".into());
+        templates[0].tags = vec!["vue3".into()];
+        templates[0].meta.vue = Some("3".into());
+        templates[1].is_default = true;
+        for format in [DocumentFormat::Yaml, DocumentFormat::Json, DocumentFormat::Toml] {
+            let document = to_document(&templates, format).unwrap();
+            assert_eq!(from_document(&document, Some(format)).unwrap(), templates);
+            assert_eq!(from_document(&document, None).unwrap(), templates);
+        }
+    }
+
+    #[test]
+    fn plans_import_with_each_strategy() {
+        let mut local = vec![template("admin"), template("same")];
+        local[0].is_default = true;
+        let mut incoming = vec![
+            TemplateInfo {
+                repo: "other/admin".into(),
+                ..template("admin")
+            },
+            template("same"),
+            template("new"),
+        ];
+        incoming[2].is_default = true;
+
+        let actions = |strategy| -> Vec<(ImportAction, Option<String>)> {
+            plan_import(&local, incoming.clone(), strategy)
+                .into_iter()
+                .map(|e| (e.action, e.renamed_to))
+                .collect()
+        };
+        assert_eq!(
+            actions(ConflictStrategy::Skip),
+            [(ImportAction::Skip, None), (ImportAction::Unchanged, None), (ImportAction::Add, None)]
+        );
+        assert_eq!(actions(ConflictStrategy::Rename)[0], (ImportAction::Rename, Some("admin-2".into())));
+
+        let plan = plan_import(&local, incoming.clone(), ConflictStrategy::Overwrite);
+        assert_eq!(plan[0].action, ImportAction::Overwrite);
+        let merged = apply_import(local.clone(), plan);
+        let aliases: Vec<_> = merged.iter().map(|t| t.alias.as_str()).collect();
+        assert_eq!(aliases, ["admin", "same", "new"]);
+        assert_eq!(merged[0].repo, "other/admin");
+        // 本地的默认模板保持不变
+        assert!(merged[0].is_default && !merged[2].is_default);
+    }
+
+    #[test]
+    fn skipped_default_does_not_block_later_defaults() {
+        let local = vec![template("admin")];
+        let mut incoming = vec![
+            TemplateInfo {
+                repo: "other/admin".into(),
+                is_default: true,
+                ..template("admin")
+            },
+            template("new"),
+            template("newer"),
+        ];
+        incoming[1].is_default = true;
+        incoming[2].is_default = true;
+        let plan = plan_import(&local, incoming.clone(), ConflictStrategy::Skip);
+        assert_eq!(plan[0].action, ImportAction::Skip);
+        // 只有第一个实际导入的默认模板保留默认
+        let defaults: Vec<_> = plan.iter().skip(1).map(|e| e.template.is_default).collect();
+        assert_eq!(defaults, [true, false]);
+
+        // 改名导入的默认模板生效
+        let plan = plan_import(&local, incoming, ConflictStrategy::Rename);
+        assert_eq!(plan[0].renamed_to.as_deref(), Some("admin-2"));
+        let defaults: Vec<_> = plan.iter().map(|e| e.template.is_default).collect();
+        assert_eq!(defaults, [true, false, false]);
+    }
+
+    #[test]
+    fn rejects_invalid_imported_entries() {
+        assert!(validate_imported(&[template("admin"), template("nuxt3")]).is_ok());
+        for alias in ["", "team/admin", "list"] {
+            assert!(validate_imported(&[template(alias)]).is_err(), "{alias}");
+        }
+        let bad_repo = TemplateInfo {
+            repo: "not a repo".into(),
+            ..template("admin")
+        };
+        assert!(validate_imported(&[bad_repo]).is_err());
+        assert!(validate_imported(&[template("admin"), template("admin")]).is_err());
+    }
+}
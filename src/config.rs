@@ -0,0 +1,352 @@
+//! cvue 配置文件，位于用户配置目录下的 `cvue/config.toml`
+//!
+//! 每个配置项都可以用 `CVUE_<分组>_<键>` 环境变量覆盖，
+//! 例如 `network.proxy` 对应 `CVUE_NETWORK_PROXY`
+
+use crate::error::ActionError;
+use crate::i18n::tr;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+use toml::{Table, Value};
+
+/// 配置文件结构
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    pub clone: CloneConfig,
+    pub auth: AuthConfig,
+    pub network: NetworkConfig,
+    pub ui: UiConfig,
+    pub registry: RegistryConfig,
+    pub github: GithubConfig,
+    /// 地址改写规则 (类似 git 的 insteadOf)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub url_rewrite: Vec<UrlRewrite>,
+    /// 订阅的远程模板库
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub subscriptions: Vec<Subscription>,
+}
+
+/// 克隆相关配置
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CloneConfig {
+    /// 克隆完成后提示使用的包管理器
+    pub package_manager: PackageManager,
+    /// 未指定目标目录时，项目创建在该目录下
+    pub target_dir: Option<String>,
+}
+
+/// 认证相关配置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AuthConfig {
+    /// 未通过 `--token` 指定时，按顺序从这些环境变量读取 GitHub Token
+    pub token_env: Vec<String>,
+}
+
+impl Default for AuthConfig {
+    fn default() -> Self {
+        AuthConfig {
+            token_env: vec!["GITHUB_TOKEN".into(), "GH_TOKEN".into()],
+        }
+    }
+}
+
+/// 网络相关配置，未设置的项使用命令行默认值
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NetworkConfig {
+    /// git 使用的 HTTP(S) 代理
+    pub proxy: Option<String>,
+    pub retries: Option<u32>,
+    pub connect_timeout: Option<u64>,
+    pub idle_timeout: Option<u64>,
+    /// `cvue check` 同时探测的仓库数量
+    pub jobs: Option<u64>,
+    /// 镜像改写规则，格式同 `--mirror FROM=TO`
+    pub mirrors: Vec<String>,
+}
+
+/// 界面相关配置
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UiConfig {
+    pub color: ColorMode,
+    pub locale: Option<String>,
+}
+
+/// 模板库相关配置
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RegistryConfig {
+    /// 模板库文件路径，默认为当前目录下的 templates.yaml
+    pub path: Option<String>,
+}
+
+/// GitHub API 相关配置
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GithubConfig {
+    /// API 地址，默认为 https://api.github.com
+    pub api_url: Option<String>,
+}
+
+/// 包管理器
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PackageManager {
+    #[default]
+    Npm,
+    Pnpm,
+    Yarn,
+    Bun,
+}
+
+impl PackageManager {
+    /// 安装依赖的命令
+    pub fn install_cmd(&self) -> &'static str {
+        match self {
+            PackageManager::Npm => "npm install",
+            PackageManager::Pnpm => "pnpm install",
+            PackageManager::Yarn => "yarn",
+            PackageManager::Bun => "bun install",
+        }
+    }
+
+    /// 启动开发服务器的命令
+    pub fn dev_cmd(&self) -> &'static str {
+        match self {
+            PackageManager::Npm => "npm run dev",
+            PackageManager::Pnpm => "pnpm dev",
+            PackageManager::Yarn => "yarn dev",
+            PackageManager::Bun => "bun run dev",
+        }
+    }
+}
+
+/// 颜色输出模式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum ColorMode {
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+/// 地址改写规则：以 `prefix` 开头的地址替换为 `replacement` 开头
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UrlRewrite {
+    pub prefix: String,
+    pub replacement: String,
+}
+
+/// 订阅的远程模板库
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Subscription {
+    /// 订阅名称，也是模板别名的命名空间
+    pub name: String,
+    /// 模板库文档的地址，或包含模板库文件的 git 仓库
+    pub url: String,
+    /// git 仓库中模板库文件的路径，默认为 templates.yaml
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+}
+
+/// 配置项的值类型
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum KeyKind {
+    Str,
+    Int,
+    List,
+}
+
+/// 可通过 `cvue config get/set/unset` 操作的配置项
+///
+/// 说明文字在消息目录的 `config.keys.<配置项>` 下
+pub const KEYS: &[(&str, KeyKind)] = &[
+    ("clone.package_manager", KeyKind::Str),
+    ("clone.target_dir", KeyKind::Str),
+    ("auth.token_env", KeyKind::List),
+    ("network.proxy", KeyKind::Str),
+    ("network.retries", KeyKind::Int),
+    ("network.connect_timeout", KeyKind::Int),
+    ("network.idle_timeout", KeyKind::Int),
+    ("network.jobs", KeyKind::Int),
+    ("network.mirrors", KeyKind::List),
+    ("ui.color", KeyKind::Str),
+    ("ui.locale", KeyKind::Str),
+    ("registry.path", KeyKind::Str),
+    ("github.api_url", KeyKind::Str),
+];
+
+/// 配置项的说明文字
+pub fn key_help(key: &str) -> String {
+    crate::i18n::t(&format!("config.keys.{}", key))
+}
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+/// 配置文件路径
+pub fn config_path() -> PathBuf {
+    dirs::config_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("cvue")
+        .join("config.toml")
+}
+
+/// 配置项对应的环境变量名
+pub fn env_name(key: &str) -> String {
+    format!("CVUE_{}", key.replace('.', "_").to_uppercase())
+}
+
+/// 读取配置文件原始内容，文件不存在时返回空表
+pub fn load_table() -> Result<Table, ActionError> {
+    match fs::read_to_string(config_path()) {
+        Ok(content) => Ok(content.parse::<Table>()?),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Table::new()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// 保存配置文件原始内容，保存前校验结构
+pub fn save_table(table: &Table) -> Result<(), ActionError> {
+    parse_table(table.clone())?;
+    let path = config_path();
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, toml::to_string_pretty(table)?)?;
+    Ok(())
+}
+
+/// 把原始表转换为类型化配置
+fn parse_table(table: Table) -> Result<Config, ActionError> {
+    Value::Table(table)
+        .try_into()
+        .map_err(|e: toml::de::Error| ActionError::Other(tr!("config.invalid", error = e.message())))
+}
+
+/// 加载配置文件并应用环境变量覆盖
+pub fn load_effective() -> Result<Config, ActionError> {
+    let mut table = load_table()?;
+    for (key, kind) in KEYS {
+        if let Ok(raw) = std::env::var(env_name(key)) {
+            set_value(&mut table, key, parse_value(*kind, &raw)?);
+        }
+    }
+    parse_table(table)
+        .map_err(|e| ActionError::Other(tr!("config.check_sources", error = e, path = config_path().display())))
+}
+
+/// 初始化全局配置，应在执行命令前调用一次
+pub fn init() -> Result<(), ActionError> {
+    let config = load_effective()?;
+    let _ = CONFIG.set(config);
+    Ok(())
+}
+
+/// 获取全局配置，未初始化时返回默认配置
+pub fn get() -> &'static Config {
+    CONFIG.get_or_init(Config::default)
+}
+
+/// 查找配置项定义
+pub fn key_kind(key: &str) -> Result<KeyKind, ActionError> {
+    KEYS.iter()
+        .find(|(k, _)| *k == key)
+        .map(|(_, kind)| *kind)
+        .ok_or_else(|| ActionError::Other(tr!("config.unknown_key", key = key)))
+}
+
+/// 按类型解析命令行或环境变量中的值
+pub fn parse_value(kind: KeyKind, raw: &str) -> Result<Value, ActionError> {
+    Ok(match kind {
+        KeyKind::Str => Value::String(raw.to_string()),
+        KeyKind::Int => Value::Integer(raw.trim().parse()?),
+        KeyKind::List => Value::Array(
+            raw.split(',')
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .map(|s| Value::String(s.to_string()))
+                .collect(),
+        ),
+    })
+}
+
+/// 读取点分路径对应的值
+pub fn get_value<'a>(table: &'a Table, key: &str) -> Option<&'a Value> {
+    let (section, name) = key.split_once('.')?;
+    table.get(section)?.as_table()?.get(name)
+}
+
+/// 设置点分路径对应的值，缺失的分组会自动创建
+pub fn set_value(table: &mut Table, key: &str, value: Value) {
+    let Some((section, name)) = key.split_once('.') else {
+        return;
+    };
+    let entry = table
+        .entry(section.to_string())
+        .or_insert_with(|| Value::Table(Table::new()));
+    if let Some(section) = entry.as_table_mut() {
+        section.insert(name.to_string(), value);
+    }
+}
+
+/// 删除点分路径对应的值，返回是否删除成功
+pub fn unset_value(table: &mut Table, key: &str) -> bool {
+    let Some((section_name, name)) = key.split_once('.') else {
+        return false;
+    };
+    let Some(section) = table.get_mut(section_name).and_then(Value::as_table_mut) else {
+        return false;
+    };
+    let removed = section.remove(name).is_some();
+    if section.is_empty() {
+        table.remove(section_name);
+    }
+    removed
+}
+
+/// 把值格式化为便于阅读的文本
+pub fn display_value(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Array(items) => items
+            .iter()
+            .map(display_value)
+            .collect::<Vec<_>>()
+            .join(","),
+        other => other.to_string(),
+    }
+}
+
+impl Config {
+    /// 按最长前缀匹配改写地址，没有匹配规则时原样返回
+    pub fn rewrite_url(&self, url: &str) -> String {
+        self.url_rewrite
+            .iter()
+            .filter(|rule| url.starts_with(&rule.prefix))
+            .max_by_key(|rule| rule.prefix.len())
+            .map(|rule| format!("{}{}", rule.replacement, &url[rule.prefix.len()..]))
+            .unwrap_or_else(|| url.to_string())
+    }
+
+    /// 按配置的环境变量顺序查找 GitHub Token
+    pub fn env_token(&self) -> Option<String> {
+        self.auth
+            .token_env
+            .iter()
+            .filter_map(|name| std::env::var(name).ok())
+            .find(|token| !token.is_empty())
+    }
+
+    /// 以点分路径读取生效的配置值
+    pub fn effective_value(&self, key: &str) -> Option<Value> {
+        let table = Table::try_from(self).ok()?;
+        get_value(&table, key).cloned()
+    }
+}
@@ -0,0 +1,154 @@
+//! 从模板仓库的 package.json 推断元数据
+
+use crate::commands::list::TemplateMeta;
+use crate::error::ActionError;
+use crate::i18n::tr;
+use serde_json::Value;
+use url::Url;
+
+/// 常见 UI 组件库的包名，按优先级排列
+const UI_LIBRARIES: &[&str] = &[
+    "element-plus",
+    "element-ui",
+    "ant-design-vue",
+    "naive-ui",
+    "vuetify",
+    "quasar",
+    "primevue",
+    "vant",
+    "@arco-design/web-vue",
+    "tdesign-vue-next",
+    "view-ui-plus",
+    "view-design",
+    "bootstrap-vue",
+];
+
+/// 构建工具: (依赖包名, 显示名称)，按优先级排列
+const BUILD_TOOLS: &[(&str, &str)] = &[
+    ("nuxt", "nuxt"),
+    ("vite", "vite"),
+    ("@vue/cli-service", "vue-cli"),
+    ("@rsbuild/core", "rsbuild"),
+    ("webpack", "webpack"),
+    ("rollup", "rollup"),
+];
+
+/// 读取仓库默认分支上的 package.json 并推断元数据
+pub fn detect_meta(repo: &str) -> Result<TemplateMeta, ActionError> {
+    let package = fetch_package_json(repo)?;
+    Ok(meta_from_package(&package))
+}
+
+/// 通过 raw.githubusercontent.com 读取 package.json
+fn fetch_package_json(repo: &str) -> Result<Value, ActionError> {
+    let url = Url::parse(&crate::utils::normalize_gh_url(repo)?)
+        .ok()
+        .filter(|url| url.host_str() == Some("github.com"))
+        .ok_or_else(|| ActionError::InvalidRepo(repo.to_string()))?;
+    let path = url.path().trim_matches('/').trim_end_matches(".git");
+    let raw = format!("https://raw.githubusercontent.com/{}/HEAD/package.json", path);
+    crate::ui::debug(&tr!("detect.fetching", url = raw));
+
+    let mut request = crate::github::http_client()?.get(&raw);
+    if let Some(token) = crate::config::get().env_token() {
+        request = request.bearer_auth(token);
+    }
+    let response = request.send()?;
+    if response.status() == reqwest::StatusCode::NOT_FOUND {
+        return Err(ActionError::Other(tr!("detect.no_package_json", repo = repo)));
+    }
+    Ok(response.error_for_status()?.json()?)
+}
+
+/// 根据 package.json 的依赖、license 和 author 推断元数据
+pub fn meta_from_package(package: &Value) -> TemplateMeta {
+    let dependency = |name: &str| {
+        ["dependencies", "devDependencies", "peerDependencies"]
+            .iter()
+            .find_map(|section| package.get(section)?.get(name)?.as_str())
+    };
+    let maintainer = match package.get("author") {
+        Some(Value::String(author)) => Some(author.clone()),
+        Some(author) => author.get("name").and_then(Value::as_str).map(str::to_string),
+        None => None,
+    };
+    TemplateMeta {
+        vue: dependency("vue").and_then(clean_version),
+        ui_library: UI_LIBRARIES
+            .iter()
+            .find(|name| dependency(name).is_some())
+            .map(|name| name.to_string()),
+        build_tool: BUILD_TOOLS
+            .iter()
+            .find(|(name, _)| dependency(name).is_some())
+            .map(|(_, tool)| tool.to_string()),
+        license: package.get("license").and_then(Value::as_str).map(str::to_string),
+        maintainer: maintainer.filter(|m| !m.is_empty()),
+    }
+}
+
+/// 去掉版本范围前缀，例如 `^3.4.21` → `3.4.21`、`>=3.2 <4` → `3.2`
+///
+/// `workspace:*`、`latest`、git 地址等不含具体版本的写法返回 None
+fn clean_version(version: &str) -> Option<String> {
+    let version = version.trim().trim_start_matches("workspace:");
+    let trimmed = version.trim_start_matches(|c: char| "^~>=<v ".contains(c));
+    let version = trimmed.split_whitespace().next()?;
+    version
+        .starts_with(|c: char| c.is_ascii_digit())
+        .then(|| version.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn cleans_version_ranges() {
+        assert_eq!(clean_version("^3.4.0").as_deref(), Some("3.4.0"));
+        assert_eq!(clean_version("~2.7").as_deref(), Some("2.7"));
+        assert_eq!(clean_version(">=3.2.0 <4").as_deref(), Some("3.2.0"));
+        assert_eq!(clean_version("workspace:^3.3.4").as_deref(), Some("3.3.4"));
+        assert_eq!(clean_version("workspace:*"), None);
+        assert_eq!(clean_version("latest"), None);
+        assert_eq!(clean_version("github:vuejs/core"), None);
+    }
+
+    #[test]
+    fn infers_meta_from_dependencies() {
+        let package = json!({
+            "dependencies": {"vue": "^3.4.0", "naive-ui": "^2.38", "element-plus": "^2.5"},
+            "devDependencies": {"vite": "^5.0.0", "webpack": "^5.0.0"},
+            "license": "MIT",
+            "author": {"name": "Vue Team", "email": "team@example.com"}
+        });
+        let meta = meta_from_package(&package);
+        assert_eq!(meta.vue.as_deref(), Some("3.4.0"));
+        // 同时存在时按优先级选择
+        assert_eq!(meta.ui_library.as_deref(), Some("element-plus"));
+        assert_eq!(meta.build_tool.as_deref(), Some("vite"));
+        assert_eq!(meta.license.as_deref(), Some("MIT"));
+        assert_eq!(meta.maintainer.as_deref(), Some("Vue Team"));
+
+        let package = json!({
+            "peerDependencies": {"vue": "~2.7"},
+            "devDependencies": {"@vue/cli-service": "~5.0.0", "webpack": "^5.0.0"},
+            "author": ""
+        });
+        let meta = meta_from_package(&package);
+        assert_eq!(meta.vue.as_deref(), Some("2.7"));
+        assert_eq!(meta.build_tool.as_deref(), Some("vue-cli"));
+        assert_eq!(meta.license, None);
+        assert_eq!(meta.maintainer, None);
+        assert!(meta.ui_library.is_none());
+
+        let meta = meta_from_package(&json!({
+            "dependencies": {"vue": "workspace:*"},
+            "devDependencies": {"webpack": "^4"}
+        }));
+        assert_eq!(meta.build_tool.as_deref(), Some("webpack"));
+        assert_eq!(meta.vue, None);
+        assert!(meta_from_package(&json!({})).is_empty());
+    }
+}
@@ -1,3 +1,4 @@
+use crate::i18n::tr;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -26,10 +27,96 @@ pub enum ActionError {
     #[error(transparent)]
     Http(#[from] reqwest::Error),
 
+    #[error(transparent)]
+    TomlDe(#[from] toml::de::Error),
+
+    #[error(transparent)]
+    TomlSer(#[from] toml::ser::Error),
+
+    #[error(transparent)]
+    Yaml(#[from] serde_yaml::Error),
+
+    #[error("{}{}", tr!("error.template_not_found", alias = alias), did_you_mean(suggestions))]
+    TemplateNotFound { alias: String, suggestions: Vec<String> },
+
+    #[error("{}", tr!("error.alias_exists", alias = .0))]
+    AliasExists(String),
+
+    #[error("{}", tr!("error.invalid_repo", repo = .0))]
+    InvalidRepo(String),
+
+    #[error("{}", tr!("error.git_failed", code = code.map(|c| c.to_string()).unwrap_or_else(|| tr!("error.git_no_code"))))]
+    GitFailed { code: Option<i32> },
+
+    #[error("{}", tr!("error.timeout", stage = stage, secs = secs))]
+    Timeout { stage: String, secs: u64 },
+
+    #[error("{}", tr!("error.check_failed", count = failed))]
+    CheckFailed { failed: usize },
+
     #[error("{0}")]
     Other(String),
 }
 
+impl ActionError {
+    /// 错误类型名称，用于结构化输出
+    pub fn kind(&self) -> &'static str {
+        match self {
+            ActionError::Io(_) => "Io",
+            ActionError::Regex(_) => "Regex",
+            ActionError::UrlParse(_) => "UrlParse",
+            ActionError::ParseInt(_) => "ParseInt",
+            ActionError::ParseFloat(_) => "ParseFloat",
+            ActionError::ParseBool(_) => "ParseBool",
+            ActionError::Json(_) => "Json",
+            ActionError::Http(_) => "Http",
+            ActionError::TomlDe(_) => "TomlDe",
+            ActionError::TomlSer(_) => "TomlSer",
+            ActionError::Yaml(_) => "Yaml",
+            ActionError::TemplateNotFound { .. } => "TemplateNotFound",
+            ActionError::AliasExists(_) => "AliasExists",
+            ActionError::InvalidRepo(_) => "InvalidRepo",
+            ActionError::GitFailed { .. } => "GitFailed",
+            ActionError::Timeout { .. } => "Timeout",
+            ActionError::CheckFailed { .. } => "CheckFailed",
+            ActionError::Other(_) => "Other",
+        }
+    }
+
+    /// 进程退出码
+    ///
+    /// | 退出码 | 含义 |
+    /// |--------|------|
+    /// | 1 | 一般错误 (IO、配置、网络等) |
+    /// | 2 | 命令行参数错误 (由 clap 产生) |
+    /// | 3 | 模板不存在 |
+    /// | 4 | 别名已存在 |
+    /// | 5 | 仓库地址无效 |
+    /// | 6 | git 执行失败 |
+    /// | 7 | git 超时 |
+    /// | 8 | 有模板未通过健康检查 |
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            ActionError::TemplateNotFound { .. } => 3,
+            ActionError::AliasExists(_) => 4,
+            ActionError::InvalidRepo(_) => 5,
+            ActionError::GitFailed { .. } => 6,
+            ActionError::Timeout { .. } => 7,
+            ActionError::CheckFailed { .. } => 8,
+            _ => 1,
+        }
+    }
+}
+
+/// 模板不存在时附加的候选别名提示
+fn did_you_mean(suggestions: &[String]) -> String {
+    if suggestions.is_empty() {
+        String::new()
+    } else {
+        tr!("error.did_you_mean", aliases = suggestions.join(", "))
+    }
+}
+
 impl From<&str> for ActionError {
     fn from(s: &str) -> Self {
         ActionError::Other(s.to_string())
@@ -0,0 +1,321 @@
+//! git 子进程封装：执行命令并把 `--progress` 输出转换成进度条
+
+use crate::error::ActionError;
+use crate::i18n::tr;
+use regex::Regex;
+use std::io::{IsTerminal, Read};
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// git 进度行解析结果
+#[derive(Debug, Clone, PartialEq)]
+pub struct Progress {
+    /// 阶段名称，例如 "Receiving objects"
+    pub phase: String,
+    pub current: u64,
+    pub total: u64,
+    /// 已接收数据量，例如 "1.20 MiB"
+    pub bytes: Option<String>,
+    /// 传输速率，例如 "512.00 KiB/s"
+    pub speed: Option<String>,
+}
+
+/// 解析一行 git 进度输出
+///
+/// 形如 `Receiving objects:  45% (450/1000), 1.20 MiB | 512.00 KiB/s`
+pub fn parse_progress(line: &str) -> Option<Progress> {
+    let re = Regex::new(
+        r"^(?:remote: )?([A-Za-z ]+):\s+\d+% \((\d+)/(\d+)\)(?:, (.+?)(?: \| (.+))?)?$",
+    )
+    .ok()?;
+    let line = line.trim();
+    let caps = re.captures(line.strip_suffix(", done.").unwrap_or(line))?;
+    Some(Progress {
+        phase: caps[1].trim().to_string(),
+        current: caps[2].parse().ok()?,
+        total: caps[3].parse().ok()?,
+        bytes: caps.get(4).map(|m| m.as_str().to_string()),
+        speed: caps.get(5).map(|m| m.as_str().to_string()),
+    })
+}
+
+/// 克隆超时设置
+#[derive(Debug, Clone)]
+pub struct CloneOptions {
+    /// 从启动到收到第一条进度的最长等待时间
+    pub connect_timeout: Duration,
+    /// 两次进度输出之间的最长间隔
+    pub idle_timeout: Duration,
+    /// HTTP(S) 代理
+    pub proxy: Option<String>,
+    /// 检出的分支、标签或提交，为空时使用远程默认分支
+    pub branch: Option<String>,
+}
+
+/// 远程仓库中的一个引用
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RemoteRef {
+    pub sha: String,
+    /// 引用全名，例如 `refs/heads/main`、`HEAD`
+    pub name: String,
+    /// 符号引用指向的引用，例如 `HEAD` → `refs/heads/main`
+    pub target: Option<String>,
+}
+
+/// 看起来像提交 SHA (7 到 40 位十六进制) 的 ref
+pub fn looks_like_commit(git_ref: &str) -> bool {
+    (7..=40).contains(&git_ref.len()) && git_ref.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// 执行 git clone
+///
+/// 始终以 `--progress` 运行以便检测卡死：stderr 为终端时显示真实进度条，
+/// 否则逐行输出 git 的信息和各阶段的完成情况。
+/// 超过连接或空闲超时会终止 git 进程并返回错误。
+/// `--branch` 不接受提交，检出提交时先克隆默认分支再 `git checkout`
+pub fn clone(url: &str, target: &Path, opts: &CloneOptions) -> Result<(), ActionError> {
+    let mut cmd = Command::new("git");
+    if let Some(proxy) = &opts.proxy {
+        cmd.arg("-c").arg(format!("http.proxy={}", proxy));
+    }
+    cmd.arg("clone").arg("--progress");
+    let commit = opts.branch.as_deref().filter(|b| looks_like_commit(b));
+    if let Some(branch) = opts.branch.as_deref().filter(|_| commit.is_none()) {
+        cmd.arg("--branch").arg(branch);
+    }
+    // 禁用交互式认证提示，私有仓库直接失败而不是等到空闲超时
+    cmd.arg(url).arg(target).env("GIT_TERMINAL_PROMPT", "0");
+    crate::ui::debug(&tr!("git.debug_exec", cmd = describe(&cmd)));
+    let mut child = cmd
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| ActionError::Other(tr!("git.spawn_failed", error = e)))?;
+    let mut stderr = child
+        .stderr
+        .take()
+        .ok_or_else(|| ActionError::Other(tr!("git.no_stderr")))?;
+
+    // 后台线程读取 stderr，主线程负责超时检测
+    let (tx, rx) = mpsc::channel::<Vec<u8>>();
+    thread::spawn(move || {
+        let mut buf = [0u8; 4096];
+        while let Ok(n) = stderr.read(&mut buf) {
+            if n == 0 || tx.send(buf[..n].to_vec()).is_err() {
+                break;
+            }
+        }
+    });
+
+    let pb = (std::io::stderr().is_terminal() && crate::ui::decorated())
+        .then(crate::ui::create_progress_bar);
+    let mut pending = Vec::new();
+    let mut connected = false;
+    let mut last_activity = Instant::now();
+    loop {
+        match rx.recv_timeout(Duration::from_millis(200)) {
+            Ok(chunk) => {
+                last_activity = Instant::now();
+                pending.extend_from_slice(&chunk);
+                // git 用 \r 刷新同一行进度，\n 结束一行
+                while let Some(pos) = pending.iter().position(|b| *b == b'\r' || *b == b'\n') {
+                    let line: Vec<u8> = pending.drain(..=pos).collect();
+                    connected |= handle_line(pb.as_ref(), String::from_utf8_lossy(&line[..pos]).trim_end());
+                }
+            }
+            Err(RecvTimeoutError::Timeout) => {
+                let (limit, stage) = if connected {
+                    (opts.idle_timeout, tr!("git.stage_idle"))
+                } else {
+                    (opts.connect_timeout, tr!("git.stage_connect"))
+                };
+                if last_activity.elapsed() > limit {
+                    let _ = child.kill();
+                    let _ = child.wait();
+                    if let Some(pb) = pb {
+                        pb.finish_and_clear();
+                    }
+                    return Err(ActionError::Timeout {
+                        stage,
+                        secs: limit.as_secs(),
+                    });
+                }
+            }
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+    }
+    if !pending.is_empty() {
+        handle_line(pb.as_ref(), String::from_utf8_lossy(&pending).trim_end());
+    }
+
+    if let Some(pb) = pb {
+        pb.finish_and_clear();
+    }
+    let status = child.wait()?;
+    if !status.success() {
+        return Err(ActionError::GitFailed { code: status.code() });
+    }
+    match commit {
+        Some(commit) => checkout(target, commit),
+        None => Ok(()),
+    }
+}
+
+/// 在克隆好的仓库中检出指定的提交 (也接受分支和标签名)
+fn checkout(repo: &Path, git_ref: &str) -> Result<(), ActionError> {
+    let mut cmd = Command::new("git");
+    cmd.arg("-C").arg(repo).args(["checkout", "--quiet", git_ref]).env("GIT_TERMINAL_PROMPT", "0");
+    crate::ui::debug(&tr!("git.debug_exec", cmd = describe(&cmd)));
+    let output = cmd
+        .stdin(Stdio::null())
+        .output()
+        .map_err(|e| ActionError::Other(tr!("git.spawn_failed", error = e)))?;
+    if output.status.success() {
+        return Ok(());
+    }
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let reason = stderr
+        .lines()
+        .rev()
+        .find(|l| !l.trim().is_empty())
+        .map(|l| crate::utils::mask_credentials(l.trim()))
+        .unwrap_or_else(|| tr!("error.git_failed", code = output.status.code().unwrap_or(-1)));
+    Err(ActionError::Other(tr!("git.checkout_failed", git_ref = git_ref, reason = reason)))
+}
+
+/// 执行 git ls-remote --symref，列出远程仓库的全部引用
+///
+/// 禁用交互式认证提示，超过 `timeout` 仍未结束时终止 git 进程
+pub fn ls_remote(url: &str, timeout: Duration, proxy: Option<&str>) -> Result<Vec<RemoteRef>, ActionError> {
+    let mut cmd = Command::new("git");
+    if let Some(proxy) = proxy {
+        cmd.arg("-c").arg(format!("http.proxy={}", proxy));
+    }
+    cmd.arg("ls-remote").arg("--symref").arg(url).env("GIT_TERMINAL_PROMPT", "0");
+    crate::ui::debug(&tr!("git.debug_exec", cmd = describe(&cmd)));
+    let mut child = cmd
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| ActionError::Other(tr!("git.spawn_failed", error = e)))?;
+
+    // 输出较多时管道可能写满，需要在等待进程的同时读取
+    let read_all = |pipe: Option<Box<dyn Read + Send>>| {
+        thread::spawn(move || {
+            let mut text = String::new();
+            if let Some(mut pipe) = pipe {
+                let _ = pipe.read_to_string(&mut text);
+            }
+            text
+        })
+    };
+    let stdout = read_all(child.stdout.take().map(|p| Box::new(p) as _));
+    let stderr = read_all(child.stderr.take().map(|p| Box::new(p) as _));
+
+    let started = Instant::now();
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        if started.elapsed() > timeout {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err(ActionError::Timeout {
+                stage: tr!("git.stage_ls_remote"),
+                secs: timeout.as_secs(),
+            });
+        }
+        thread::sleep(Duration::from_millis(50));
+    };
+    let stdout = stdout.join().unwrap_or_default();
+    let stderr = stderr.join().unwrap_or_default();
+    for line in stderr.lines() {
+        crate::ui::trace(&format!("git: {}", line));
+    }
+    if !status.success() {
+        // 用 git 的最后一行错误信息说明原因，例如仓库不存在或需要认证
+        let reason = stderr
+            .lines()
+            .rev()
+            .find(|l| !l.trim().is_empty())
+            .map(|l| crate::utils::mask_credentials(l.trim()))
+            .unwrap_or_else(|| tr!("error.git_failed", code = status.code().unwrap_or(-1)));
+        return Err(ActionError::Other(reason));
+    }
+    Ok(parse_ls_remote(&stdout))
+}
+
+/// 解析 `ls-remote --symref` 的输出
+///
+/// `ref: refs/heads/main\tHEAD` 这样的符号引用行记录到随后同名引用的 `target`
+pub fn parse_ls_remote(output: &str) -> Vec<RemoteRef> {
+    let mut refs: Vec<RemoteRef> = Vec::new();
+    let mut targets: Vec<(String, String)> = Vec::new();
+    for line in output.lines() {
+        let Some((sha, name)) = line.split_once('\t') else {
+            continue;
+        };
+        let name = name.trim().to_string();
+        match sha.strip_prefix("ref:") {
+            Some(target) => targets.push((name, target.trim().to_string())),
+            None => refs.push(RemoteRef {
+                sha: sha.trim().to_string(),
+                target: targets.iter().find(|(n, _)| *n == name).map(|(_, t)| t.clone()),
+                name,
+            }),
+        }
+    }
+    refs
+}
+
+/// 处理一行 git 输出：进度行更新进度条，其它行隐藏认证信息后打印
+///
+/// 返回该行是否为进度行
+fn handle_line(pb: Option<&indicatif::ProgressBar>, line: &str) -> bool {
+    if line.is_empty() {
+        return false;
+    }
+    let progress = parse_progress(line);
+    // 错误信息中可能带有含 token 的仓库地址
+    let line = &crate::utils::mask_credentials(line);
+    crate::ui::trace(&format!("git: {}", line));
+    let is_progress = progress.is_some();
+    match (pb, progress) {
+        (Some(pb), Some(progress)) => {
+            pb.set_length(progress.total);
+            pb.set_position(progress.current);
+            pb.set_prefix(progress.phase);
+            let detail = match (progress.bytes, progress.speed) {
+                (Some(bytes), Some(speed)) => format!("{} | {}", bytes, speed),
+                (Some(bytes), None) => bytes,
+                _ => String::new(),
+            };
+            pb.set_message(detail);
+        }
+        (Some(pb), None) => pb.println(line),
+        // 非终端只输出每个阶段的完成行，避免刷屏
+        (None, Some(progress)) if progress.current < progress.total => {}
+        // 安静模式只保留 git 的错误和警告
+        (None, _) if crate::ui::verbosity() == crate::ui::Verbosity::Quiet => {
+            if ["fatal:", "error:", "warning:"].iter().any(|p| line.starts_with(p)) {
+                eprintln!("{}", line);
+            }
+        }
+        (None, _) => eprintln!("{}", line),
+    }
+    is_progress
+}
+
+/// 生成用于调试输出的命令行，隐藏地址中的认证信息
+fn describe(cmd: &Command) -> String {
+    std::iter::once(cmd.get_program())
+        .chain(cmd.get_args())
+        .map(|arg| crate::utils::mask_credentials(&arg.to_string_lossy()))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
@@ -0,0 +1,478 @@
+//! GitHub API 客户端：读取模板仓库的上游信息
+//!
+//! 响应按 ETag 缓存在用户缓存目录下的 `cvue/github`，再次请求时带上
+//! `If-None-Match`，未变化的响应不计入 API 限额。限额用完后在重置前
+//! 直接使用缓存，不再发起请求
+
+use crate::error::ActionError;
+use crate::i18n::tr;
+use reqwest::header::{ACCEPT, ETAG, IF_NONE_MATCH};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use url::Url;
+
+/// 默认的 API 地址，可通过配置 `github.api_url` 修改 (例如 GitHub Enterprise)
+pub const DEFAULT_API_URL: &str = "https://api.github.com";
+
+/// 最多显示的标签数量
+const TAG_LIMIT: usize = 5;
+
+/// 读取的标签数量：接口按名称排序，需要多读一些再按版本号排序
+const TAG_FETCH_LIMIT: usize = 100;
+
+/// 上游仓库信息
+#[derive(Debug, Clone, Serialize)]
+pub struct RepoInfo {
+    pub stars: u64,
+    /// 最后一次推送时间 (ISO 8601)
+    pub pushed_at: Option<String>,
+    pub default_branch: Option<String>,
+    pub archived: bool,
+    /// 许可证的 SPDX 标识
+    pub license: Option<String>,
+    /// 未关闭的 issue 数量 (GitHub 的统计包含 PR)
+    pub open_issues: u64,
+    /// 最新的几个标签
+    pub latest_tags: Vec<String>,
+}
+
+/// 仓库搜索结果中的一项
+#[derive(Debug, Clone, Serialize)]
+pub struct SearchHit {
+    /// `owner/repo`
+    pub full_name: String,
+    pub description: Option<String>,
+    pub stars: u64,
+    pub topics: Vec<String>,
+    pub license: Option<String>,
+    pub archived: bool,
+}
+
+/// 缓存的 API 响应
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedResponse {
+    etag: Option<String>,
+    /// 缓存时间 (Unix 秒)
+    fetched_at: u64,
+    body: Value,
+}
+
+/// 读取模板仓库的上游信息
+///
+/// `offline` 时只读取缓存。不是 GitHub 仓库时返回 `Ok(None)`
+pub fn repo_info(repo: &str, offline: bool) -> Result<Option<RepoInfo>, ActionError> {
+    let Some(path) = repo_path(repo) else {
+        return Ok(None);
+    };
+    let api = api_url();
+    let repo = get_json(&api, &format!("repos/{}", path), offline, true)?;
+    // 标签读取失败不影响其它信息
+    let tags = match get_json(&api, &format!("repos/{}/tags?per_page={}", path, TAG_FETCH_LIMIT), offline, true) {
+        Ok(tags) => tags,
+        Err(e) => {
+            crate::ui::debug(&e.to_string());
+            Value::Null
+        }
+    };
+    Ok(Some(parse_repo_info(&repo, &tags)))
+}
+
+/// 按主题搜索仓库，每个主题单独搜索后合并去重，按 star 数从多到少排列
+///
+/// `query` 为附加的关键字，`api` 为 API 地址 (通常为 [`api_url`])
+pub fn search_repositories(api: &str, query: Option<&str>, topics: &[String], limit: usize) -> Result<Vec<SearchHit>, ActionError> {
+    let mut hits: Vec<SearchHit> = Vec::new();
+    for topic in topics {
+        let q = match query.map(str::trim).filter(|q| !q.is_empty()) {
+            Some(query) => format!("{} topic:{}", query, topic),
+            None => format!("topic:{}", topic),
+        };
+        let path = format!(
+            "search/repositories?q={}&sort=stars&order=desc&per_page={}",
+            url::form_urlencoded::byte_serialize(q.as_bytes()).collect::<String>(),
+            limit.clamp(1, 100)
+        );
+        // 搜索结果变化快，不缓存
+        let body = get_json(api, &path, false, false)?;
+        for item in body.get("items").and_then(Value::as_array).into_iter().flatten() {
+            let hit = parse_search_hit(item);
+            if !hit.full_name.is_empty() && !hits.iter().any(|h| h.full_name == hit.full_name) {
+                hits.push(hit);
+            }
+        }
+    }
+    hits.sort_by(|a, b| b.stars.cmp(&a.stars).then_with(|| a.full_name.cmp(&b.full_name)));
+    hits.truncate(limit);
+    Ok(hits)
+}
+
+/// 从搜索结果中提取需要的字段
+fn parse_search_hit(item: &Value) -> SearchHit {
+    let info = parse_repo_info(item, &Value::Null);
+    SearchHit {
+        full_name: item.get("full_name").and_then(Value::as_str).unwrap_or_default().to_string(),
+        description: item
+            .get("description")
+            .and_then(Value::as_str)
+            .map(str::trim)
+            .filter(|d| !d.is_empty())
+            .map(str::to_string),
+        stars: info.stars,
+        topics: item
+            .get("topics")
+            .and_then(Value::as_array)
+            .map(|topics| topics.iter().filter_map(Value::as_str).map(str::to_string).collect())
+            .unwrap_or_default(),
+        license: info.license,
+        archived: info.archived,
+    }
+}
+
+/// 从 API 响应中提取需要的字段
+///
+/// 标签与 `cvue refs` 一样按版本号从新到旧排列，不是版本号的标签排在后面
+pub fn parse_repo_info(repo: &Value, tags: &Value) -> RepoInfo {
+    let text = |value: &Value, key: &str| value.get(key).and_then(Value::as_str).map(str::to_string);
+    RepoInfo {
+        stars: repo.get("stargazers_count").and_then(Value::as_u64).unwrap_or(0),
+        pushed_at: text(repo, "pushed_at"),
+        default_branch: text(repo, "default_branch"),
+        archived: repo.get("archived").and_then(Value::as_bool).unwrap_or(false),
+        license: repo
+            .get("license")
+            .and_then(|l| text(l, "spdx_id"))
+            .filter(|id| id != "NOASSERTION"),
+        open_issues: repo.get("open_issues_count").and_then(Value::as_u64).unwrap_or(0),
+        latest_tags: latest_tags(tags),
+    }
+}
+
+/// 按版本号取最新的几个标签
+fn latest_tags(tags: &Value) -> Vec<String> {
+    let mut names: Vec<String> = tags
+        .as_array()
+        .map(|tags| tags.iter().filter_map(|t| t.get("name")?.as_str().map(str::to_string)).collect())
+        .unwrap_or_default();
+    names.sort_by_key(|name| std::cmp::Reverse(crate::commands::refs::parse_version(name)));
+    names.truncate(TAG_LIMIT);
+    names
+}
+
+/// GitHub 仓库的 `owner/repo`，其它仓库返回 `None`
+pub fn repo_path(repo: &str) -> Option<String> {
+    let url = Url::parse(&crate::utils::normalize_gh_url(repo).ok()?).ok()?;
+    if url.host_str() != Some("github.com") {
+        return None;
+    }
+    let mut segments = url.path_segments()?.filter(|s| !s.is_empty());
+    let owner = segments.next()?;
+    let name = segments.next()?.trim_end_matches(".git");
+    Some(format!("{}/{}", owner, name))
+}
+
+/// 访问 GitHub 使用的 HTTP 客户端，应用配置中的代理和连接超时
+pub fn http_client() -> Result<reqwest::blocking::Client, ActionError> {
+    let config = crate::config::get();
+    let mut builder = reqwest::blocking::Client::builder()
+        .user_agent(concat!("cvue/", env!("CARGO_PKG_VERSION")))
+        .connect_timeout(Duration::from_secs(config.network.connect_timeout.unwrap_or(30)))
+        .timeout(Duration::from_secs(60));
+    if let Some(proxy) = &config.network.proxy {
+        builder = builder.proxy(reqwest::Proxy::all(proxy)?);
+    }
+    Ok(builder.build()?)
+}
+
+/// 配置的 API 地址
+pub fn api_url() -> String {
+    crate::config::get()
+        .github
+        .api_url
+        .clone()
+        .unwrap_or_else(|| DEFAULT_API_URL.to_string())
+        .trim_end_matches('/')
+        .to_string()
+}
+
+/// 请求 API，`use_cache` 时按 ETag 缓存响应
+fn get_json(base: &str, path: &str, offline: bool, use_cache: bool) -> Result<Value, ActionError> {
+    let url = format!("{}/{}", base, path);
+    let cache_path = cache_file(&url).filter(|_| use_cache);
+    let cached = cache_path.as_deref().and_then(read_cache);
+    if offline {
+        return cached
+            .map(|c| c.body)
+            .ok_or_else(|| ActionError::Other(tr!("github.no_cache", url = url)));
+    }
+    // 搜索 API 与其它 API 的限额分开计算，一个用完不影响另一个
+    let mut resource = rate_limit_resource(path).to_string();
+    if let Some(reset) = rate_limit_reset(base, &resource) {
+        return cached_or_rate_limited(cached, reset);
+    }
+
+    crate::ui::debug(&tr!("github.fetching", url = url));
+    let mut request = http_client()?
+        .get(&url)
+        .header(ACCEPT, "application/vnd.github+json");
+    if let Some(token) = crate::config::get().env_token() {
+        request = request.bearer_auth(token);
+    }
+    if let Some(etag) = cached.as_ref().and_then(|c| c.etag.as_ref()) {
+        request = request.header(IF_NONE_MATCH, etag);
+    }
+    let response = match request.send() {
+        Ok(response) => response,
+        // 网络不可用时退回到缓存
+        Err(e) => {
+            return match cached {
+                Some(cached) => {
+                    crate::ui::print_warning(&tr!("github.network_fallback", error = e));
+                    Ok(cached.body)
+                }
+                None => Err(e.into()),
+            }
+        }
+    };
+
+    let header = |name: &str| {
+        response
+            .headers()
+            .get(name)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string)
+    };
+    let remaining = header("x-ratelimit-remaining").and_then(|v| v.parse::<u64>().ok());
+    let reset = header("x-ratelimit-reset").and_then(|v| v.parse::<u64>().ok());
+    if let Some(name) = header("x-ratelimit-resource") {
+        resource = name;
+    }
+    if remaining == Some(0) {
+        if let Some(reset) = reset {
+            save_rate_limit(base, &resource, reset);
+        }
+    }
+    match response.status() {
+        StatusCode::NOT_MODIFIED => {
+            crate::ui::debug(&tr!("github.not_modified", url = url));
+            cached
+                .map(|c| c.body)
+                .ok_or_else(|| ActionError::Other(tr!("github.no_cache", url = url)))
+        }
+        StatusCode::FORBIDDEN | StatusCode::TOO_MANY_REQUESTS
+            if remaining == Some(0) || header("retry-after").is_some() =>
+        {
+            let reset = reset.unwrap_or_else(|| {
+                now() + header("retry-after").and_then(|v| v.parse().ok()).unwrap_or(60)
+            });
+            save_rate_limit(base, &resource, reset);
+            cached_or_rate_limited(cached, reset)
+        }
+        StatusCode::NOT_FOUND => Err(ActionError::Other(tr!("github.not_found", url = url))),
+        _ => {
+            let response = response.error_for_status()?;
+            let etag = response
+                .headers()
+                .get(ETAG)
+                .and_then(|v| v.to_str().ok())
+                .map(str::to_string);
+            let body: Value = response.json()?;
+            if let Some(path) = &cache_path {
+                write_cache(path, &CachedResponse {
+                    etag,
+                    fetched_at: now(),
+                    body: body.clone(),
+                });
+            }
+            Ok(body)
+        }
+    }
+}
+
+/// 限额用完时使用缓存，没有缓存则报告重置时间
+fn cached_or_rate_limited(cached: Option<CachedResponse>, reset: u64) -> Result<Value, ActionError> {
+    let minutes = reset.saturating_sub(now()).div_ceil(60);
+    match cached {
+        Some(cached) => {
+            crate::ui::print_warning(&tr!(
+                "github.rate_limited_cached",
+                minutes = minutes,
+                age = now().saturating_sub(cached.fetched_at) / 60
+            ));
+            Ok(cached.body)
+        }
+        None => Err(ActionError::Other(tr!("github.rate_limited", minutes = minutes))),
+    }
+}
+
+/// 当前 Unix 时间 (秒)
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// 缓存目录
+fn cache_dir() -> Option<PathBuf> {
+    Some(dirs::cache_dir()?.join("cvue").join("github"))
+}
+
+/// 把地址转换为缓存文件名
+///
+/// 可读部分替换了特殊字符并限制长度，不同地址可能相同，因此追加完整地址的哈希
+fn cache_key(url: &str) -> String {
+    let key: String = url
+        .split_once("://")
+        .map_or(url, |(_, rest)| rest)
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '.' { c } else { '_' })
+        .take(100)
+        .collect();
+    // FNV-1a，结果不随 Rust 版本变化
+    let hash = url
+        .bytes()
+        .fold(0xcbf29ce484222325u64, |hash, b| (hash ^ u64::from(b)).wrapping_mul(0x100000001b3));
+    format!("{}-{:016x}.json", key, hash)
+}
+
+/// 地址对应的缓存文件
+fn cache_file(url: &str) -> Option<PathBuf> {
+    Some(cache_dir()?.join(cache_key(url)))
+}
+
+/// 读取缓存，文件不存在或损坏时返回 `None`
+fn read_cache(path: &Path) -> Option<CachedResponse> {
+    serde_json::from_str(&fs::read_to_string(path).ok()?).ok()
+}
+
+/// 写入缓存，失败时只输出调试信息
+fn write_cache(path: &Path, cached: &CachedResponse) {
+    let result = path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::write(path, serde_json::to_string(cached).unwrap_or_default()));
+    if let Err(e) = result {
+        crate::ui::debug(&tr!("github.cache_write_failed", path = path.display(), error = e));
+    }
+}
+
+/// 请求所属的限额类别，与响应头 `x-ratelimit-resource` 一致
+fn rate_limit_resource(path: &str) -> &'static str {
+    if path.starts_with("search/") {
+        "search"
+    } else {
+        "core"
+    }
+}
+
+/// 限额重置时间的记录文件，每个 API 地址的每类限额一个
+fn rate_limit_file(base: &str, resource: &str) -> Option<PathBuf> {
+    let resource: String = resource.chars().filter(|c| c.is_ascii_alphanumeric() || *c == '_').collect();
+    Some(cache_dir()?.join(format!("rate-limit-{}-{}", resource, cache_key(base))))
+}
+
+/// 记录限额重置时间
+fn save_rate_limit(base: &str, resource: &str, reset: u64) {
+    if let Some(path) = rate_limit_file(base, resource) {
+        let _ = path.parent().map_or(Ok(()), fs::create_dir_all);
+        let _ = fs::write(path, reset.to_string());
+    }
+}
+
+/// 限额用完且尚未重置时返回重置时间
+fn rate_limit_reset(base: &str, resource: &str) -> Option<u64> {
+    let path = rate_limit_file(base, resource)?;
+    let reset: u64 = fs::read_to_string(&path).ok()?.trim().parse().ok()?;
+    if reset > now() {
+        Some(reset)
+    } else {
+        let _ = fs::remove_file(path);
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn extracts_repo_path() {
+        assert_eq!(repo_path("vuejs/create-vue").as_deref(), Some("vuejs/create-vue"));
+        assert_eq!(
+            repo_path("https://github.com/nuxt/starter.git").as_deref(),
+            Some("nuxt/starter")
+        );
+        assert_eq!(repo_path("not a repo"), None);
+        assert_eq!(repo_path("https://gitlab.com/team/app.git"), None);
+        assert_eq!(
+            repo_path("https://github.com/vuejs/core/tree/main/packages").as_deref(),
+            Some("vuejs/core")
+        );
+    }
+
+    #[test]
+    fn names_cache_files_by_url() {
+        let key = cache_key("https://api.github.com/repos/vuejs/core?per_page=5");
+        assert!(key.starts_with("api.github.com_repos_vuejs_core_per_page_5-") && key.ends_with(".json"));
+        assert_eq!(key, cache_key("https://api.github.com/repos/vuejs/core?per_page=5"));
+        // 替换字符后相同的地址使用不同的文件
+        assert_ne!(cache_key("https://h/a/b_c"), cache_key("https://h/a_b/c"));
+        assert_ne!(cache_key("https://h/x?q=1"), cache_key("https://h/x?q=2"));
+        assert!(cache_key(&format!("https://h/{}", "a".repeat(500))).len() < 130);
+    }
+
+    #[test]
+    fn tracks_rate_limits_per_resource() {
+        assert_eq!(rate_limit_resource("search/repositories?q=topic%3Avue"), "search");
+        assert_eq!(rate_limit_resource("repos/vuejs/core"), "core");
+        let search = rate_limit_file("https://api.github.com", "search");
+        let core = rate_limit_file("https://api.github.com", "core");
+        assert_ne!(search, core);
+        assert_eq!(rate_limit_file("https://api.github.com", "../core"), core);
+    }
+
+    #[test]
+    fn falls_back_to_cache_when_rate_limited() {
+        let cached = CachedResponse {
+            etag: None,
+            fetched_at: now(),
+            body: json!({"stargazers_count": 1}),
+        };
+        let reset = now() + 120;
+        assert_eq!(cached_or_rate_limited(Some(cached), reset).unwrap(), json!({"stargazers_count": 1}));
+        assert!(cached_or_rate_limited(None, reset).is_err());
+    }
+
+    #[test]
+    fn parses_repo_info() {
+        let repo = json!({
+            "stargazers_count": 42,
+            "pushed_at": "2024-05-01T12:00:00Z",
+            "default_branch": "main",
+            "archived": true,
+            "license": {"spdx_id": "MIT"},
+            "open_issues_count": 3,
+        });
+        // 接口按名称排序
+        let tags = json!([
+            {"name": "v9.0.0"}, {"name": "v10.1.0"}, {"name": "v10.0.0"}, {"name": "nightly"},
+            {"name": "v2.0.0"}, {"name": "v1.0.0"}, {"name": "v0.9"},
+        ]);
+        let info = parse_repo_info(&repo, &tags);
+        assert_eq!(info.stars, 42);
+        assert!(info.archived);
+        assert_eq!(info.license.as_deref(), Some("MIT"));
+        assert_eq!(info.latest_tags, ["v10.1.0", "v10.0.0", "v9.0.0", "v2.0.0", "v1.0.0"]);
+        let named = parse_repo_info(&repo, &json!([{"name": "nightly"}, {"name": "v1"}, {"name": "beta"}]));
+        assert_eq!(named.latest_tags, ["v1", "nightly", "beta"]);
+
+        let other = parse_repo_info(&json!({"license": {"spdx_id": "NOASSERTION"}}), &Value::Null);
+        assert_eq!(other.license, None);
+        assert!(other.latest_tags.is_empty());
+    }
+}
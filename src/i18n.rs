@@ -0,0 +1,200 @@
+//! 多语言消息目录
+//!
+//! 消息按语言存放在 `locales/<语言>.yaml` 中，编译时嵌入。
+//! 语言按 `--lang` > 配置 `ui.locale` > `LC_ALL`/`LC_MESSAGES`/`LANG` 的顺序选择，
+//! 都没有时使用简体中文
+
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::sync::OnceLock;
+
+/// 默认语言
+pub const DEFAULT_LOCALE: &str = "zh-CN";
+
+/// 所有内置语言: (语言代码, 消息目录)
+pub const LOCALES: &[(&str, &str)] = &[
+    ("zh-CN", include_str!("../locales/zh-CN.yaml")),
+    ("en", include_str!("../locales/en.yaml")),
+];
+
+static LOCALE: OnceLock<&'static str> = OnceLock::new();
+static CATALOGS: OnceLock<HashMap<&'static str, HashMap<String, String>>> = OnceLock::new();
+
+/// 取消息并替换 `{name}` 占位符
+///
+/// ```ignore
+/// tr!("list.added", alias = alias)
+/// ```
+macro_rules! tr {
+    ($key:expr) => {
+        $crate::i18n::t($key)
+    };
+    ($key:expr, $($name:ident = $value:expr),+ $(,)?) => {
+        $crate::i18n::tf($key, &[$((stringify!($name), &$value as &dyn std::fmt::Display)),+])
+    };
+}
+pub(crate) use tr;
+
+/// 解析所有消息目录
+fn catalogs() -> &'static HashMap<&'static str, HashMap<String, String>> {
+    CATALOGS.get_or_init(|| {
+        LOCALES
+            .iter()
+            .map(|(code, content)| (*code, serde_yaml::from_str(content).unwrap_or_default()))
+            .collect()
+    })
+}
+
+/// 把 `en_US.UTF-8`、`zh-TW` 等写法归一到内置语言代码
+pub fn normalize(lang: &str) -> Option<&'static str> {
+    let lang = lang
+        .split(['.', '@'])
+        .next()
+        .unwrap_or("")
+        .replace('_', "-")
+        .to_lowercase();
+    if lang.starts_with("zh") {
+        Some("zh-CN")
+    } else if lang == "en" || lang.starts_with("en-") {
+        Some("en")
+    } else {
+        None
+    }
+}
+
+/// 按优先级选择语言
+pub fn detect(preferred: &[Option<&str>]) -> &'static str {
+    let env_langs = ["LC_ALL", "LC_MESSAGES", "LANG"].map(|name| std::env::var(name).ok());
+    preferred
+        .iter()
+        .copied()
+        .chain(env_langs.iter().map(|v| v.as_deref()))
+        .flatten()
+        .find_map(normalize)
+        .unwrap_or(DEFAULT_LOCALE)
+}
+
+/// 设置当前语言，应在执行命令前调用一次
+pub fn init(locale: &'static str) {
+    let _ = LOCALE.set(locale);
+}
+
+/// 当前语言
+pub fn locale() -> &'static str {
+    LOCALE.get().copied().unwrap_or(DEFAULT_LOCALE)
+}
+
+/// 查找消息，当前语言缺失时回退到默认语言
+pub fn lookup(key: &str) -> Option<&'static str> {
+    let catalogs = catalogs();
+    [locale(), DEFAULT_LOCALE]
+        .iter()
+        .find_map(|code| catalogs.get(code)?.get(key))
+        .map(String::as_str)
+}
+
+/// 取消息，找不到时返回键本身
+pub fn t(key: &str) -> String {
+    lookup(key).unwrap_or(key).to_string()
+}
+
+/// 取消息并替换占位符
+pub fn tf(key: &str, args: &[(&str, &dyn Display)]) -> String {
+    args.iter().fold(t(key), |msg, (name, value)| {
+        msg.replace(&format!("{{{}}}", name), &value.to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use regex::Regex;
+    use std::collections::BTreeSet;
+
+    fn keys(code: &str) -> BTreeSet<&String> {
+        catalogs()[code].keys().collect()
+    }
+
+    #[test]
+    fn every_key_exists_in_every_locale() {
+        let base = keys(DEFAULT_LOCALE);
+        assert!(!base.is_empty());
+        for (code, _) in LOCALES {
+            let other = keys(code);
+            let missing: Vec<_> = base.difference(&other).collect();
+            let extra: Vec<_> = other.difference(&base).collect();
+            assert!(missing.is_empty(), "{} 缺少: {:?}", code, missing);
+            assert!(extra.is_empty(), "{} 多出: {:?}", code, extra);
+        }
+    }
+
+    #[test]
+    fn placeholders_match_across_locales() {
+        let re = Regex::new(r"\{(\w+)\}").unwrap();
+        let placeholders = |text: &str| -> BTreeSet<String> {
+            re.captures_iter(text).map(|c| c[1].to_string()).collect()
+        };
+        for (key, text) in &catalogs()[DEFAULT_LOCALE] {
+            for (code, _) in LOCALES {
+                let other = &catalogs()[code][key];
+                assert_eq!(placeholders(text), placeholders(other), "{} 的占位符在 {} 中不一致", key, code);
+            }
+        }
+    }
+
+    #[test]
+    fn every_key_used_in_source_exists() {
+        let re = Regex::new(r#"\btr!\(\s*"([^"]+)""#).unwrap();
+        let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+        let mut stack = vec![dir];
+        while let Some(dir) = stack.pop() {
+            for entry in std::fs::read_dir(dir).unwrap() {
+                let path = entry.unwrap().path();
+                if path.is_dir() {
+                    stack.push(path);
+                } else if path.extension().is_some_and(|e| e == "rs") {
+                    let content = std::fs::read_to_string(&path).unwrap();
+                    for caps in re.captures_iter(&content) {
+                        assert!(
+                            catalogs()[DEFAULT_LOCALE].contains_key(&caps[1]),
+                            "{} 使用了未定义的消息 {}",
+                            path.display(),
+                            &caps[1]
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn every_command_and_argument_has_help() {
+        use clap::CommandFactory;
+        fn walk(cmd: &clap::Command, key: String) {
+            assert!(lookup(&key).is_some(), "缺少帮助文本 {}", key);
+            for arg in cmd.get_arguments() {
+                let arg_key = format!("{}.{}", key, arg.get_id());
+                assert!(lookup(&arg_key).is_some(), "缺少帮助文本 {}", arg_key);
+            }
+            for sub in cmd.get_subcommands() {
+                walk(sub, format!("{}.{}", key, sub.get_name()));
+            }
+        }
+        walk(&crate::cli::Cli::command(), "help.cvue".into());
+    }
+
+    #[test]
+    fn every_config_key_has_help() {
+        for (key, _) in crate::config::KEYS {
+            let help_key = format!("config.keys.{}", key);
+            assert!(lookup(&help_key).is_some(), "缺少配置说明 {}", help_key);
+        }
+    }
+
+    #[test]
+    fn normalizes_system_locales() {
+        assert_eq!(normalize("en_US.UTF-8"), Some("en"));
+        assert_eq!(normalize("zh_TW"), Some("zh-CN"));
+        assert_eq!(normalize("C"), None);
+    }
+}
@@ -0,0 +1,223 @@
+//! 模板库的修改历史
+//!
+//! 每次保存模板库时在同目录下的 `<文件名>.history.jsonl` 追加一条记录，包含时间、
+//! 用户、命令、变更的别名和修改前的完整模板列表，`cvue undo` 据此恢复之前的状态
+
+use crate::commands::list::TemplateInfo;
+use crate::error::ActionError;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// 最多保留的记录数，超出时丢弃最早的记录
+const MAX_ENTRIES: usize = 200;
+
+/// 一条修改记录
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JournalEntry {
+    /// 递增的编号
+    pub id: u64,
+    /// Unix 时间戳 (秒)
+    pub time: u64,
+    pub user: String,
+    /// 触发修改的命令行
+    pub command: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub added: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub removed: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub changed: Vec<String>,
+    /// 本次撤销的记录编号，普通修改为空
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub reverts: Vec<u64>,
+    /// 修改前的模板列表
+    pub before: Vec<TemplateInfo>,
+}
+
+/// 模板库对应的历史文件，例如 `templates.yaml` → `templates.history.jsonl`
+pub fn journal_path(templates_path: &str) -> PathBuf {
+    Path::new(templates_path).with_extension("history.jsonl")
+}
+
+/// 读取全部记录，从旧到新排列。无法解析的行会被忽略
+pub fn load(templates_path: &str) -> Vec<JournalEntry> {
+    fs::read_to_string(journal_path(templates_path))
+        .map(|content| content.lines().filter_map(|line| serde_json::from_str(line).ok()).collect())
+        .unwrap_or_default()
+}
+
+/// 记录一次修改，没有实际变化的普通修改不记录
+pub fn record(
+    templates_path: &str,
+    before: &[TemplateInfo],
+    after: &[TemplateInfo],
+    reverts: Vec<u64>,
+) -> Result<(), ActionError> {
+    let (added, removed, changed) = diff(before, after);
+    if reverts.is_empty() && added.is_empty() && removed.is_empty() && changed.is_empty() {
+        return Ok(());
+    }
+    let mut entries = load(templates_path);
+    entries.push(JournalEntry {
+        id: entries.last().map_or(1, |e| e.id + 1),
+        time: SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs()),
+        user: current_user(),
+        command: command_line(std::env::args().skip(1)),
+        added,
+        removed,
+        changed,
+        reverts,
+        before: before.to_vec(),
+    });
+    let skip = entries.len().saturating_sub(MAX_ENTRIES);
+    let mut content = String::new();
+    for entry in &entries[skip..] {
+        content.push_str(&serde_json::to_string(entry)?);
+        content.push('\n');
+    }
+    fs::write(journal_path(templates_path), content)?;
+    Ok(())
+}
+
+/// 按别名对比两个模板列表，返回新增、删除和修改的别名
+pub fn diff(before: &[TemplateInfo], after: &[TemplateInfo]) -> (Vec<String>, Vec<String>, Vec<String>) {
+    let find = |list: &[TemplateInfo], tpl: &TemplateInfo| list.iter().position(|t| t.alias == tpl.alias);
+    let added = after
+        .iter()
+        .filter(|t| find(before, t).is_none())
+        .map(|t| t.alias.clone())
+        .collect();
+    let removed = before
+        .iter()
+        .filter(|t| find(after, t).is_none())
+        .map(|t| t.alias.clone())
+        .collect();
+    let changed = after
+        .iter()
+        .filter(|t| find(before, t).is_some_and(|i| before[i] != **t))
+        .map(|t| t.alias.clone())
+        .collect();
+    (added, removed, changed)
+}
+
+/// 已被撤销的记录编号
+pub fn undone_ids(entries: &[JournalEntry]) -> HashSet<u64> {
+    entries.iter().flat_map(|e| e.reverts.iter().copied()).collect()
+}
+
+/// 最近 `steps` 条可以撤销的记录，从新到旧排列
+///
+/// 撤销记录本身和已被撤销的记录会被跳过，因此连续执行 `undo` 会依次向前回退
+pub fn undo_candidates(entries: &[JournalEntry], steps: usize) -> Vec<&JournalEntry> {
+    let undone = undone_ids(entries);
+    entries
+        .iter()
+        .rev()
+        .filter(|e| e.reverts.is_empty() && !undone.contains(&e.id))
+        .take(steps)
+        .collect()
+}
+
+/// 触发修改的命令行，隐藏其中地址的认证信息
+fn command_line(args: impl Iterator<Item = String>) -> String {
+    let command = std::iter::once("cvue".to_string()).chain(args).collect::<Vec<_>>().join(" ");
+    crate::utils::mask_credentials(&command)
+}
+
+/// 当前用户名
+fn current_user() -> String {
+    std::env::var("USER")
+        .or_else(|_| std::env::var("USERNAME"))
+        .unwrap_or_else(|_| "unknown".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::list::test_template;
+
+    fn template(alias: &str, description: &str) -> TemplateInfo {
+        TemplateInfo {
+            description: description.into(),
+            ..test_template(alias)
+        }
+    }
+
+    fn entry(id: u64, reverts: Vec<u64>) -> JournalEntry {
+        JournalEntry {
+            id,
+            time: 0,
+            user: "test".into(),
+            command: String::new(),
+            added: Vec::new(),
+            removed: Vec::new(),
+            changed: Vec::new(),
+            reverts,
+            before: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn diffs_by_alias() {
+        let before = vec![template("a", "A"), template("b", "B")];
+        let after = vec![template("b", "B2"), template("c", "C")];
+        assert_eq!(diff(&before, &after), (vec!["c".into()], vec!["a".into()], vec!["b".into()]));
+    }
+
+    /// 临时目录中的模板库路径
+    fn registry_path() -> (PathBuf, String) {
+        let dir = crate::utils::private_temp_dir("cvue-journal-test").unwrap();
+        let path = dir.join("templates.yaml").display().to_string();
+        (dir, path)
+    }
+
+    #[test]
+    fn records_only_real_changes() {
+        let (dir, path) = registry_path();
+        let before = vec![template("a", "A")];
+        record(&path, &before, &before, Vec::new()).unwrap();
+        assert!(load(&path).is_empty());
+        record(&path, &before, &[template("a", "A2")], Vec::new()).unwrap();
+        // 撤销即使没有差异也要记录，否则无法知道哪些记录已被撤销
+        record(&path, &before, &before, vec![1]).unwrap();
+        let entries = load(&path);
+        assert_eq!(entries.iter().map(|e| e.id).collect::<Vec<_>>(), [1, 2]);
+        assert_eq!(entries[0].changed, ["a"]);
+        assert_eq!(entries[0].before, before);
+        assert_eq!(entries[1].reverts, [1]);
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn keeps_only_the_latest_entries() {
+        let (dir, path) = registry_path();
+        let total = MAX_ENTRIES as u64 + 3;
+        for i in 0..total {
+            let before = vec![template("a", &i.to_string())];
+            let after = vec![template("a", &(i + 1).to_string())];
+            record(&path, &before, &after, Vec::new()).unwrap();
+        }
+        let entries = load(&path);
+        assert_eq!(entries.len(), MAX_ENTRIES);
+        assert_eq!(entries.first().map(|e| e.id), Some(4));
+        assert_eq!(entries.last().map(|e| e.id), Some(total));
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn masks_credentials_in_command() {
+        let args = ["add", "-a", "x", "-r", "https://ghp_secret@github.com/team/x.git"].map(String::from);
+        assert_eq!(command_line(args.into_iter()), "cvue add -a x -r https://****@github.com/team/x.git");
+    }
+
+    #[test]
+    fn undo_steps_back_over_undone_entries() {
+        let entries = vec![entry(1, vec![]), entry(2, vec![]), entry(3, vec![]), entry(4, vec![3])];
+        let ids = |steps| undo_candidates(&entries, steps).iter().map(|e| e.id).collect::<Vec<_>>();
+        assert_eq!(ids(1), [2]);
+        assert_eq!(ids(5), [2, 1]);
+    }
+}
@@ -1,16 +1,31 @@
 mod cli;
 mod commands;
+mod config;
+mod detect;
 mod error;
+mod git;
+mod github;
+mod i18n;
+mod journal;
 mod utils;
 mod ui;
 
 fn main() {
-    // 显示应用标志
-    ui::print_logo();
-    
     // 运行CLI
     if let Err(e) = cli::run() {
-        ui::print_error(&format!("错误: {}", e));
-        std::process::exit(1);
+        match ui::output_format() {
+            ui::OutputFormat::Json | ui::OutputFormat::Yaml => {
+                let report = serde_json::json!({
+                    "error": {
+                        "code": e.exit_code(),
+                        "kind": e.kind(),
+                        "message": e.to_string(),
+                    }
+                });
+                let _ = ui::print_data(&report);
+            }
+            _ => ui::print_error(&i18n::tr!("common.error_prefix", error = e)),
+        }
+        std::process::exit(e.exit_code());
     }
 }
\ No newline at end of file
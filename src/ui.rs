@@ -1,46 +1,156 @@
+use crate::error::ActionError;
+use crate::i18n::tr;
 use colored::*;
 use dialoguer::{theme::ColorfulTheme, Confirm};
 use indicatif::{ProgressBar, ProgressStyle};
-use std::io::{self};
+use serde::Serialize;
+use std::io::{self, IsTerminal, Write};
+use std::sync::OnceLock;
 use std::time::Duration;
-use tabled::{settings::style::Style, Table, Tabled};
+use tabled::{builder::Builder, settings::style::Style, Table};
 use terminal_size::terminal_size;
 use unicode_width::UnicodeWidthStr;
 
+/// 输出格式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum OutputFormat {
+    // 彩色表格 (默认)
+    #[default]
+    Table,
+    // 制表符分隔的纯文本，每行一条记录
+    Plain,
+    Json,
+    Yaml,
+}
+
+static OUTPUT: OnceLock<OutputFormat> = OnceLock::new();
+
+/// 设置全局输出格式，应在执行命令前调用一次
+pub fn set_output_format(format: OutputFormat) {
+    let _ = OUTPUT.set(format);
+}
+
+/// 当前输出格式
+pub fn output_format() -> OutputFormat {
+    OUTPUT.get().copied().unwrap_or_default()
+}
+
+/// 日志级别
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Verbosity {
+    /// 只输出数据和错误
+    Quiet,
+    #[default]
+    Normal,
+    /// `-v`: 额外输出执行的 git 命令等调试信息
+    Debug,
+    /// `-vv`: 额外输出 git 的原始输出
+    Trace,
+}
+
+static VERBOSITY: OnceLock<Verbosity> = OnceLock::new();
+
+/// 设置全局日志级别，应在执行命令前调用一次
+pub fn set_verbosity(verbosity: Verbosity) {
+    let _ = VERBOSITY.set(verbosity);
+}
+
+/// 当前日志级别
+pub fn verbosity() -> Verbosity {
+    VERBOSITY.get().copied().unwrap_or_default()
+}
+
+/// 是否输出标题、进度等装饰内容 (仅表格模式且非安静模式)
+pub fn decorated() -> bool {
+    output_format() == OutputFormat::Table && verbosity() > Verbosity::Quiet
+}
+
+/// 输出调试信息 (`-v`)
+pub fn debug(msg: &str) {
+    if verbosity() >= Verbosity::Debug {
+        eprintln!("{} {}", "[debug]".dimmed(), msg.dimmed());
+    }
+}
+
+/// 输出跟踪信息 (`-vv`)
+pub fn trace(msg: &str) {
+    if verbosity() >= Verbosity::Trace {
+        eprintln!("{} {}", "[trace]".dimmed(), msg.dimmed());
+    }
+}
+
+/// 以 JSON/YAML 输出结构化数据到 stdout
+pub fn print_data<T: Serialize>(value: &T) -> Result<(), ActionError> {
+    let text = match output_format() {
+        OutputFormat::Yaml => serde_yaml::to_string(value)?,
+        _ => serde_json::to_string_pretty(value)? + "\n",
+    };
+    // 管道提前关闭 (例如 `| head`) 时不视为错误
+    match io::stdout().write_all(text.as_bytes()) {
+        Err(e) if e.kind() == io::ErrorKind::BrokenPipe => Ok(()),
+        result => Ok(result?),
+    }
+}
+
+/// 设置颜色输出
+///
+/// `auto` 模式下遵循 `NO_COLOR` 环境变量，stdout 不是终端时也关闭颜色
+pub fn apply_color(mode: crate::config::ColorMode) {
+    let enabled = match mode {
+        crate::config::ColorMode::Always => true,
+        crate::config::ColorMode::Never => false,
+        crate::config::ColorMode::Auto => {
+            std::env::var_os("NO_COLOR").filter(|v| !v.is_empty()).is_none()
+                && io::stdout().is_terminal()
+        }
+    };
+    colored::control::set_override(enabled);
+}
+
 // Message Components
+// 状态消息统一写到 stderr，stdout 只保留数据
 pub fn print_success(msg: &str) {
-    println!("{} {}", "✓".bright_green(), msg.green());
+    if verbosity() > Verbosity::Quiet {
+        eprintln!("{} {}", "✓".bright_green(), msg.green());
+    }
 }
 
 pub fn print_error(msg: &str) {
-    println!("{} {}", "✗".bright_red(), msg.red());
+    eprintln!("{} {}", "✗".bright_red(), msg.red());
 }
 
 pub fn print_warning(msg: &str) {
-    println!("{} {}", "⚠".bright_yellow(), msg.yellow());
+    if verbosity() > Verbosity::Quiet {
+        eprintln!("{} {}", "⚠".bright_yellow(), msg.yellow());
+    }
 }
 
 pub fn print_info(msg: &str) {
-    println!("{} {}", "ℹ".bright_blue(), msg.blue());
+    if verbosity() > Verbosity::Quiet {
+        eprintln!("{} {}", "ℹ".bright_blue(), msg.blue());
+    }
 }
 
 // Title Component
 pub fn print_title(title: &str) {
+    if !decorated() {
+        return;
+    }
     let width = title.width() + 4;
-    println!("╭{}╮", "─".repeat(width));
-    println!(
+    eprintln!("╭{}╮", "─".repeat(width));
+    eprintln!(
         "│ {}{} │",
         title.bright_cyan().underline(),
         " ".repeat(title.width())
     );
-    println!("╰{}╯", "~".repeat(width));
+    eprintln!("╰{}╯", "~".repeat(width));
 }
 
 /// 打印详情卡片
 ///
 /// # Arguments
 /// * `items` - 键值对列表 (标签, 值)
-pub fn print_detail_card(items: &[(&str, String)]) {
+pub fn print_detail_card(items: &[(String, String)]) {
     let max_label_width = items
         .iter()
         .map(|(label, _)| label.width())
@@ -68,64 +178,138 @@ pub fn print_divider(length: usize, color: Color) {
     println!("{}", "─".repeat(length).color(color));
 }
 
-#[derive(Tabled)]
-struct TemplateDisplay {
-    #[tabled(rename = "别名")]
-    alias: String,
-    #[tabled(rename = "仓库")]
-    repo: String,
-    #[tabled(rename = "描述")]
-    description: String,
-    #[tabled(rename = "默认")]
-    is_default: String,
+/// 交互式选择列表中的模板选项: 别名 - 描述 #标签 [默认][已弃用]
+pub fn template_option(tpl: &crate::commands::list::TemplateInfo) -> String {
+    let tags: Vec<String> = tpl.tags.iter().map(|t| format!("#{}", t)).collect();
+    format!(
+        "{} - {} {} {}{}",
+        tpl.alias.bright_cyan(),
+        tpl.description,
+        tags.join(" ").bright_magenta(),
+        if tpl.is_default {
+            tr!("common.default_tag").bright_green()
+        } else {
+            "".into()
+        },
+        if tpl.deprecated {
+            tr!("common.deprecated_tag").yellow()
+        } else {
+            "".into()
+        }
+    )
+}
+
+/// 模板的纯文本行: 别名、仓库、是否默认、描述、标签(逗号分隔)，以制表符分隔
+pub fn template_plain_line(tpl: &crate::commands::list::TemplateInfo) -> String {
+    format!(
+        "{}\t{}\t{}\t{}\t{}",
+        tpl.alias,
+        tpl.repo,
+        tpl.is_default,
+        tpl.description,
+        tpl.tags.join(",")
+    )
 }
 
 /// 打印模板信息表格
 ///
 /// # Arguments
 /// * `templates` - 模板信息切片
+/// * `grouped` - 是否按标签分组显示，模板应已按分组排序
 ///
 /// # 示例
 /// ```
-/// print_template_table(&templates);
+/// print_template_table(&templates, true);
 /// ```
-pub fn print_template_table(templates: &[crate::commands::list::TemplateInfo]) {
+pub fn print_template_table(templates: &[crate::commands::list::TemplateInfo], grouped: bool) {
     if templates.is_empty() {
-        print_warning("没有模板，请先添加或运行 'cvue init' 初始化常用模板。");
+        print_warning(&tr!("common.no_templates"));
         return;
     }
 
-    // 转换模板数据为显示格式
-    let display_templates: Vec<TemplateDisplay> = templates
-        .iter()
-        .map(|tpl| {
-            let desc = if let Some((width, _)) = terminal_size() {
-                // 根据终端宽度限制描述长度
-                let max_width = (width.0 as usize).min(80) / 3; // 分配1/3宽度给描述
-                if tpl.description.chars().count() > max_width {
-                    let truncated: String = tpl.description.chars().take(max_width - 3).collect();
-                    format!("{}...", truncated)
-                } else {
-                    tpl.description.clone()
-                }
+    // 都没有标签时不显示分组和标签列
+    let has_tags = templates.iter().any(|t| !t.tags.is_empty());
+    let grouped = grouped && has_tags;
+
+    // 表头随界面语言变化
+    let mut builder = Builder::default();
+    let mut header = Vec::new();
+    if grouped {
+        header.push(tr!("table.group"));
+    }
+    header.extend([tr!("table.alias"), tr!("table.repo"), tr!("table.description")]);
+    if has_tags {
+        header.push(tr!("table.tags"));
+    }
+    // 有元数据时显示技术栈摘要
+    let has_stack = templates.iter().any(|t| !t.meta.stack().is_empty());
+    if has_stack {
+        header.push(tr!("table.stack"));
+    }
+    // 有订阅的模板时显示来源
+    let has_source = templates.iter().any(|t| t.source.is_some());
+    if has_source {
+        header.push(tr!("table.source"));
+    }
+    header.push(tr!("table.default"));
+    builder.push_record(header);
+    let mut last_group = None;
+    for tpl in templates {
+        let desc = if let Some((width, _)) = terminal_size() {
+            // 根据终端宽度限制描述长度
+            let max_width = (width.0 as usize).min(80) / 3; // 分配1/3宽度给描述
+            if tpl.description.chars().count() > max_width {
+                let truncated: String = tpl.description.chars().take(max_width - 3).collect();
+                format!("{}...", truncated)
             } else {
-                // 默认截断长度
-                tpl.description.chars().take(40).collect()
-            };
-
-            TemplateDisplay {
-                alias: tpl.alias.clone(),
-                repo: tpl.repo.clone(),
-                description: desc,
-                is_default: if tpl.is_default {
-                    "✓".bright_green().to_string()
-                } else {
-                    "".to_string()
-                },
+                tpl.description.clone()
             }
-        })
-        .collect(); // 创建并格式化表格
-    let mut table = Table::new(display_templates);
+        } else {
+            // 默认截断长度
+            tpl.description.chars().take(40).collect()
+        };
+
+        let mut record = Vec::new();
+        if grouped {
+            // 分组名只在组内第一行显示
+            let group = crate::commands::list::group_name(tpl);
+            record.push(if last_group == Some(group) {
+                String::new()
+            } else if group.is_empty() {
+                tr!("table.untagged").dimmed().to_string()
+            } else {
+                group.bright_magenta().to_string()
+            });
+            last_group = Some(group);
+        }
+        // 已弃用的模板划掉别名并标注
+        let alias = if tpl.deprecated {
+            format!("{} {}", tpl.alias.dimmed().strikethrough(), tr!("table.deprecated_mark").yellow())
+        } else {
+            tpl.alias.clone()
+        };
+        record.extend([alias, tpl.repo.clone(), desc]);
+        if has_tags {
+            record.push(tpl.tags.join(", "));
+        }
+        if has_stack {
+            record.push(tpl.meta.stack());
+        }
+        if has_source {
+            record.push(match &tpl.source {
+                Some(source) => source.bright_blue().to_string(),
+                None => tr!("table.local").dimmed().to_string(),
+            });
+        }
+        record.push(if tpl.is_default {
+            "✓".bright_green().to_string()
+        } else {
+            "".to_string()
+        });
+        builder.push_record(record);
+    }
+    // 创建并格式化表格
+    let mut table = builder.build();
     table.with(Style::modern());
 
     // 斑马条纹效果
@@ -144,6 +328,219 @@ pub fn print_template_table(templates: &[crate::commands::list::TemplateInfo]) {
     }
 }
 
+/// 打印健康检查结果表格
+pub fn print_check_table(results: &[crate::commands::check::CheckResult]) {
+    use crate::commands::check::CheckStatus;
+    let mut builder = Builder::default();
+    builder.push_record([
+        tr!("table.alias"),
+        tr!("table.repo"),
+        "ref".to_string(),
+        tr!("table.status"),
+        tr!("table.detail"),
+        tr!("table.elapsed"),
+    ]);
+    for result in results {
+        let status = match result.status {
+            CheckStatus::Ok => result.status.label().bright_green(),
+            CheckStatus::RefMissing => result.status.label().yellow(),
+            _ => result.status.label().bright_red(),
+        };
+        // 成功时显示提交的短 SHA，失败时显示原因
+        let detail = match (&result.message, &result.commit) {
+            (Some(message), _) => message.clone(),
+            (None, Some(commit)) => commit.chars().take(12).collect(),
+            _ => String::new(),
+        };
+        builder.push_record([
+            result.alias.clone(),
+            result.repo.clone(),
+            result
+                .git_ref
+                .clone()
+                .or_else(|| result.version_req.clone())
+                .unwrap_or_else(|| "HEAD".to_string()),
+            status.to_string(),
+            detail,
+            format!("{:.1}s", result.elapsed_ms as f64 / 1000.0),
+        ]);
+    }
+    let mut table = builder.build();
+    table.with(Style::modern());
+    println!("{}", table);
+}
+
+/// 打印标签和分支表格，满足版本约束的标签标记 ✓
+pub fn print_ref_table(refs: &[crate::commands::refs::RefEntry]) {
+    use crate::commands::refs::RefKind;
+    let has_req = refs.iter().any(|r| r.matches.is_some());
+    let mut builder = Builder::default();
+    let mut header = vec![tr!("table.ref_name"), tr!("table.ref_kind"), tr!("table.commit")];
+    if has_req {
+        header.push(tr!("table.matches"));
+    }
+    builder.push_record(header);
+    for r in refs {
+        let kind = match r.kind {
+            RefKind::Tag => tr!("refs.kind_tag"),
+            RefKind::Branch if r.is_default => format!("{} {}", tr!("refs.kind_branch"), tr!("refs.default_mark")),
+            RefKind::Branch => tr!("refs.kind_branch"),
+        };
+        let mut record = vec![r.name.clone(), kind, r.commit.chars().take(12).collect()];
+        if has_req {
+            record.push(match r.matches {
+                Some(true) => "✓".bright_green().to_string(),
+                _ => String::new(),
+            });
+        }
+        builder.push_record(record);
+    }
+    let mut table = builder.build();
+    table.with(Style::modern());
+    println!("{}", table);
+}
+
+/// 打印仓库搜索结果表格
+pub fn print_discover_table(hits: &[crate::github::SearchHit]) {
+    let mut builder = Builder::default();
+    builder.push_record([tr!("table.repo"), "★".to_string(), tr!("table.description"), tr!("table.tags")]);
+    for hit in hits {
+        let mut name = hit.full_name.clone();
+        if hit.archived {
+            name = format!("{} {}", name.dimmed(), tr!("discover.archived_mark").yellow());
+        }
+        builder.push_record([
+            name,
+            hit.stars.to_string(),
+            hit.description.clone().unwrap_or_default().chars().take(60).collect(),
+            hit.topics.join(", "),
+        ]);
+    }
+    let mut table = builder.build();
+    table.with(Style::modern());
+    println!("{}", table);
+}
+
+/// 打印订阅的模板库及同步状态
+pub fn print_subscription_table(statuses: &[crate::commands::registry::SubscriptionStatus]) {
+    let mut builder = Builder::default();
+    builder.push_record([
+        tr!("table.name"),
+        tr!("table.source_url"),
+        tr!("table.template_count"),
+        tr!("table.synced"),
+    ]);
+    for status in statuses {
+        let url = match &status.path {
+            Some(path) => format!("{} ({})", status.url, path),
+            None => status.url.clone(),
+        };
+        builder.push_record([
+            status.name.clone(),
+            url,
+            status.templates.map(|n| n.to_string()).unwrap_or_else(|| "-".to_string()),
+            match status.synced_secs_ago {
+                Some(secs) => format_age(secs),
+                None => tr!("registry.never_synced").yellow().to_string(),
+            },
+        ]);
+    }
+    let mut table = builder.build();
+    table.with(Style::modern());
+    println!("{}", table);
+}
+
+/// 打印导入预览: 每个别名的处理方式
+pub fn print_import_table(plan: &[crate::commands::registry::ImportEntry]) {
+    use crate::commands::registry::ImportAction;
+    let mut builder = Builder::default();
+    builder.push_record([tr!("table.alias"), tr!("table.repo"), tr!("table.action")]);
+    for entry in plan {
+        let action = match entry.action {
+            ImportAction::Add => tr!("registry.action_add").bright_green(),
+            ImportAction::Overwrite => tr!("registry.action_overwrite").yellow(),
+            ImportAction::Rename => {
+                tr!("registry.action_rename", alias = entry.renamed_to.as_deref().unwrap_or_default()).bright_cyan()
+            }
+            ImportAction::Skip => tr!("registry.action_skip").bright_red(),
+            ImportAction::Unchanged => tr!("registry.action_unchanged").dimmed(),
+        };
+        builder.push_record([entry.alias.clone(), entry.template.repo.clone(), action.to_string()]);
+    }
+    let mut table = builder.build();
+    table.with(Style::modern());
+    println!("{}", table);
+}
+
+/// 打印修改历史，已撤销的记录变暗
+pub fn print_history_table(log: &[crate::commands::history::LogEntry]) {
+    let mut builder = Builder::default();
+    builder.push_record([
+        "#".to_string(),
+        tr!("table.time"),
+        tr!("table.user"),
+        tr!("table.command"),
+        tr!("table.changes"),
+    ]);
+    for entry in log {
+        let mut changes: Vec<String> = Vec::new();
+        if !entry.reverts.is_empty() {
+            let ids: Vec<String> = entry.reverts.iter().map(|id| format!("#{}", id)).collect();
+            changes.push(tr!("history.reverts", ids = ids.join(", ")).bright_cyan().to_string());
+        }
+        changes.extend(entry.added.iter().map(|a| format!("+{}", a).bright_green().to_string()));
+        changes.extend(entry.removed.iter().map(|a| format!("-{}", a).bright_red().to_string()));
+        changes.extend(entry.changed.iter().map(|a| format!("~{}", a).yellow().to_string()));
+        let mut id = entry.id.to_string();
+        let mut command = entry.command.clone();
+        if entry.undone {
+            id = id.dimmed().to_string();
+            command = format!("{} {}", command.dimmed().strikethrough(), tr!("history.undone_mark").yellow());
+        }
+        builder.push_record([id, format_age(entry.age()), entry.user.clone(), command, changes.join(" ")]);
+    }
+    let mut table = builder.build();
+    table.with(Style::modern());
+    println!("{}", table);
+}
+
+/// 把秒数格式化为 "3 分钟前" 这样的相对时间
+pub fn format_age(secs: u64) -> String {
+    match secs {
+        0..60 => tr!("time.just_now"),
+        60..3600 => tr!("time.minutes_ago", count = secs / 60),
+        3600..86400 => tr!("time.hours_ago", count = secs / 3600),
+        _ => tr!("time.days_ago", count = secs / 86400),
+    }
+}
+
+/// 发现模板多选列表中的一项: owner/repo ★stars - 描述
+pub fn discover_option(hit: &crate::github::SearchHit) -> String {
+    let mut option = format!("{} {}", hit.full_name, format!("★{}", hit.stars).bright_yellow());
+    if let Some(description) = &hit.description {
+        let description: String = description.chars().take(60).collect();
+        option.push_str(&format!(" - {}", description));
+    }
+    if hit.archived {
+        option.push_str(&format!(" {}", tr!("discover.archived_mark").yellow()));
+    }
+    option
+}
+
+/// 版本选择列表中的一项: 名称 + 类型 + 满足约束标记
+pub fn ref_option(r: &crate::commands::refs::RefEntry) -> String {
+    use crate::commands::refs::RefKind;
+    let kind = match r.kind {
+        RefKind::Tag => tr!("refs.kind_tag"),
+        RefKind::Branch => tr!("refs.kind_branch"),
+    };
+    let mut option = format!("{} {}", r.name, format!("({})", kind).dimmed());
+    if r.matches == Some(true) {
+        option.push_str(&format!(" {}", "✓".bright_green()));
+    }
+    option
+}
+
 /// 打印模板详情
 ///
 /// # Arguments
@@ -153,29 +550,106 @@ pub fn print_template_table(templates: &[crate::commands::list::TemplateInfo]) {
 /// ```
 /// print_template_detail(&template);
 /// ```
-pub fn print_template_detail(template: &crate::commands::list::TemplateInfo) {
-    print_title(&format!("模板详情: {}", template.alias)); // 使用表格显示详情
-    let mut table = Table::new(vec![
-        ["别名".bright_cyan().to_string(), template.alias.clone()],
-        ["仓库".bright_cyan().to_string(), template.repo.clone()],
+pub fn print_template_detail(
+    template: &crate::commands::list::TemplateInfo,
+    upstream: Option<&crate::github::RepoInfo>,
+) {
+    print_title(&tr!("detail.title", alias = template.alias)); // 使用表格显示详情
+    let mut rows = vec![
+        [tr!("table.alias").bright_cyan().to_string(), template.alias.clone()],
+        [tr!("table.repo").bright_cyan().to_string(), template.repo.clone()],
         [
-            "描述".bright_cyan().to_string(),
+            tr!("table.description").bright_cyan().to_string(),
             template.description.clone(),
         ],
         [
-            "默认".bright_cyan().to_string(),
-            if template.is_default {
-                "是".bright_green().to_string()
-            } else {
-                "否".normal().to_string()
-            },
+            tr!("table.tags").bright_cyan().to_string(),
+            template.tags.join(", "),
         ],
+    ];
+    if let Some(source) = &template.source {
+        rows.push([tr!("table.source").bright_cyan().to_string(), source.clone()]);
+    }
+    if let Some(git_ref) = &template.git_ref {
+        rows.push([tr!("detail.ref").bright_cyan().to_string(), git_ref.clone()]);
+    }
+    if let Some(req) = &template.version_req {
+        rows.push([tr!("detail.version_req").bright_cyan().to_string(), req.clone()]);
+    }
+    // 只显示已设置的元数据
+    let meta = &template.meta;
+    for (label, value) in [
+        ("detail.vue", &meta.vue),
+        ("detail.ui_library", &meta.ui_library),
+        ("detail.build_tool", &meta.build_tool),
+        ("detail.license", &meta.license),
+        ("detail.maintainer", &meta.maintainer),
+    ] {
+        if let Some(value) = value {
+            rows.push([crate::i18n::t(label).bright_cyan().to_string(), value.clone()]);
+        }
+    }
+    if template.deprecated {
+        let reason = template.deprecation_reason.as_deref().unwrap_or_default();
+        rows.push([
+            tr!("detail.deprecated").bright_cyan().to_string(),
+            format!("{} {}", tr!("common.yes"), reason).yellow().to_string(),
+        ]);
+        if let Some(replacement) = &template.replaced_by {
+            rows.push([tr!("detail.replaced_by").bright_cyan().to_string(), replacement.clone()]);
+        }
+    }
+    rows.push([
+        tr!("table.default").bright_cyan().to_string(),
+        if template.is_default {
+            tr!("common.yes").bright_green().to_string()
+        } else {
+            tr!("common.no").normal().to_string()
+        },
     ]);
+    if let Some(info) = upstream {
+        rows.extend(upstream_rows(info));
+    }
+    let mut table = Table::new(rows);
 
     table.with(Style::ascii());
     println!("{}", table);
 }
 
+/// 上游仓库信息的详情行
+fn upstream_rows(info: &crate::github::RepoInfo) -> Vec<[String; 2]> {
+    let label = |key: &str| crate::i18n::t(key).bright_cyan().to_string();
+    let mut rows = vec![[label("detail.stars"), info.stars.to_string()]];
+    if let Some(pushed_at) = &info.pushed_at {
+        // 只显示日期部分
+        rows.push([label("detail.pushed_at"), pushed_at.chars().take(10).collect()]);
+    }
+    if let Some(branch) = &info.default_branch {
+        rows.push([label("detail.default_branch"), branch.clone()]);
+    }
+    rows.push([
+        label("detail.archived"),
+        if info.archived {
+            tr!("common.yes").bright_red().to_string()
+        } else {
+            tr!("common.no")
+        },
+    ]);
+    if let Some(license) = &info.license {
+        rows.push([label("detail.upstream_license"), license.clone()]);
+    }
+    rows.push([label("detail.open_issues"), info.open_issues.to_string()]);
+    if !info.latest_tags.is_empty() {
+        rows.push([label("detail.latest_tags"), info.latest_tags.join(", ")]);
+    }
+    rows
+}
+
+/// 是否可以进行交互式选择 (stdin 和 stderr 都是终端)
+pub fn interactive() -> bool {
+    io::stdin().is_terminal() && io::stderr().is_terminal()
+}
+
 /// 交互式确认
 pub fn confirm(message: &str) -> io::Result<bool> {
     let theme = ColorfulTheme::default();
@@ -183,7 +657,7 @@ pub fn confirm(message: &str) -> io::Result<bool> {
         .with_prompt(message)
         .default(false)
         .interact()
-        .map_err(|e| io::Error::new(io::ErrorKind::Other, e))
+        .map_err(io::Error::other)
 }
 
 /// 创建进度条
@@ -200,6 +674,9 @@ pub fn confirm(message: &str) -> io::Result<bool> {
 /// // 处理完成后调用 spinner.finish();
 /// ```
 pub fn create_spinner(message: &str) -> ProgressBar {
+    if !decorated() {
+        return ProgressBar::hidden();
+    }
     let pb = ProgressBar::new_spinner();
     pb.set_style(
         ProgressStyle::default_spinner()
@@ -212,6 +689,24 @@ pub fn create_spinner(message: &str) -> ProgressBar {
     pb
 }
 
+/// 创建 git 克隆进度条
+///
+/// 前缀显示当前阶段，消息显示已接收数据量和速率
+pub fn create_progress_bar() -> ProgressBar {
+    if !decorated() {
+        return ProgressBar::hidden();
+    }
+    let pb = ProgressBar::new(0);
+    pb.set_style(
+        ProgressStyle::default_bar()
+            .template("{spinner:.green} {prefix:<18} [{bar:40.cyan/blue}] {pos}/{len} {msg}")
+            .unwrap()
+            .progress_chars("#>-"),
+    );
+    pb.enable_steady_tick(Duration::from_millis(100));
+    pb
+}
+
 /// 打印应用标志
 ///
 /// # 示例
@@ -233,17 +728,17 @@ pub fn print_logo() {
         .bright_cyan()
     );
 
-    println!("{}", "Vue模板管理CLI工具".bright_green().bold());
+    println!("{}", tr!("logo.tagline").bright_green().bold());
 
     // 版本信息卡片
     print_detail_card(&[
-        ("版本", "0.1.0".to_string()),
-        ("作者", "HYH".to_string()),
+        (tr!("logo.version"), env!("CARGO_PKG_VERSION").to_string()),
+        (tr!("logo.author"), "HYH".to_string()),
         (
-            "仓库",
+            tr!("logo.repo"),
             "https://github.com/HYH0309/cvue".bright_blue().to_string(),
         ),
-        ("许可证", "MIT".to_string()),
+        (tr!("logo.license"), "MIT".to_string()),
     ]);
 
     print_divider(40, Color::Cyan);
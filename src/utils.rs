@@ -1,19 +1,27 @@
 use crate::error::ActionError;
+use crate::i18n::tr;
 use regex::Regex;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 use url::Url;
 
 /// 标准化 GitHub URL (owner/repo → https URL)
+///
+/// 本地仓库 (绝对路径或 `file://` 地址) 原样返回，便于离线使用和测试
 pub fn normalize_gh_url(repo: &str) -> Result<String, ActionError> {
     if let Some(caps) = Regex::new(r"^([\w.-]+)/([\w.-]+)$")?.captures(repo) {
         Ok(format!("https://github.com/{}/{}.git", &caps[1], &caps[2]))
+    } else if Path::new(repo).is_absolute() && Path::new(repo).exists() {
+        Ok(repo.to_string())
     } else if let Ok(url) = Url::parse(repo) {
-        if url.host_str() == Some("github.com") {
+        if url.host_str() == Some("github.com") || url.scheme() == "file" {
             Ok(repo.to_string())
         } else {
-            Err(ActionError::Other("无效的 GitHub URL".into()))
+            Err(ActionError::InvalidRepo(repo.to_string()))
         }
     } else {
-        Err(ActionError::Other("无效的 GitHub URL".into()))
+        Err(ActionError::InvalidRepo(repo.to_string()))
     }
 }
 
@@ -27,23 +35,556 @@ pub fn add_gh_auth(url: &str, token: Option<&str>) -> Result<String, ActionError
             }
             Ok(authed)
         },
-        Some(_) => Err(ActionError::Other("URL 已包含认证信息".into())),
+        Some(_) => Err(ActionError::Other(tr!("utils.url_has_auth"))),
         None => Ok(url.to_string()),
     }
 }
 
+/// 隐藏地址中的认证信息 (`https://token@host` → `https://****@host`)
+pub fn mask_credentials(url: &str) -> String {
+    match Regex::new(r"://[^@/\s]+@") {
+        Ok(re) => re.replace_all(url, "://****@").into_owned(),
+        Err(_) => url.to_string(),
+    }
+}
+
+/// 镜像改写规则，按前缀把原始地址替换为镜像地址
+///
+/// 命令行格式为 `FROM=TO`，例如 `https://github.com/=https://gitee.com/`
+#[derive(Debug, Clone, PartialEq)]
+pub struct MirrorRule {
+    pub from: String,
+    pub to: String,
+}
+
+impl MirrorRule {
+    /// 地址以 `from` 开头时返回改写后的地址
+    pub fn apply(&self, url: &str) -> Option<String> {
+        url.strip_prefix(&self.from)
+            .map(|rest| format!("{}{}", self.to, rest))
+    }
+}
+
+impl FromStr for MirrorRule {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once('=') {
+            Some((from, to)) if !from.is_empty() && !to.is_empty() => Ok(MirrorRule {
+                from: from.to_string(),
+                to: to.to_string(),
+            }),
+            _ => Err(tr!("utils.invalid_mirror", rule = s)),
+        }
+    }
+}
+
 /// 验证项目名称
 pub fn check_name(name: &str) -> Result<(), ActionError> {
     const RESERVED: &[&str] = &["con", "prn", "aux", "nul",
         "com1", "com2", "com3", "com4", "com5", "com6", "com7", "com8", "com9",
         "lpt1", "lpt2", "lpt3", "lpt4", "lpt5", "lpt6", "lpt7", "lpt8", "lpt9"];
     match name {
-        "" => Err(ActionError::Other("项目名称不能为空".into())),
-        n if n.len() > 255 => Err(ActionError::Other("名称过长(最多255字符)".into())),
+        "" => Err(ActionError::Other(tr!("utils.name_empty"))),
+        n if n.len() > 255 => Err(ActionError::Other(tr!("utils.name_too_long"))),
         n if n.chars().any(|c| matches!(c, '<'|'>'|':'|'"'|'|'|'?'|'*'|'\\'|'/')) =>
-            Err(ActionError::Other("包含无效字符".into())),
+            Err(ActionError::Other(tr!("utils.name_invalid_chars"))),
         n if RESERVED.contains(&n.to_lowercase().as_str()) =>
-            Err(ActionError::Other("保留名称不能使用".into())),
+            Err(ActionError::Other(tr!("utils.name_reserved"))),
         _ => Ok(())
     }
-}
\ No newline at end of file
+}
+/// 根据仓库地址推导默认目标目录名
+///
+/// 去掉查询参数、`.git` 后缀和末尾斜杠；对子目录模板 (`owner/repo/sub/dir`
+/// 或 `.../tree/<ref>/sub/dir`) 取子目录的最后一级
+pub fn default_dir_name(repo: &str) -> String {
+    let trimmed = repo.split(['?', '#']).next().unwrap_or(repo).trim_end_matches('/');
+    let path = match trimmed.split_once("://") {
+        Some((_, rest)) => rest.split_once('/').map(|(_, p)| p).unwrap_or(""),
+        None => match trimmed.split_once(':') {
+            // scp 风格: git@host:owner/repo.git
+            Some((host, p)) if host.contains('@') => p,
+            _ => trimmed,
+        },
+    };
+    let mut segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty() && *s != "-").collect();
+    if let Some(pos) = segments.iter().skip(2).position(|s| matches!(*s, "tree" | "blob")) {
+        let pos = pos + 2;
+        let end = (pos + 2).min(segments.len());
+        segments.drain(pos..end);
+    }
+    segments
+        .last()
+        .map(|s| s.trim_end_matches(".git"))
+        .filter(|s| !s.is_empty())
+        .unwrap_or("template")
+        .to_string()
+}
+
+/// 解析目标路径，只校验最后一级目录名
+pub fn resolve_target(target: &str) -> Result<PathBuf, ActionError> {
+    let path = PathBuf::from(target);
+    let name = path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .ok_or_else(|| ActionError::Other(tr!("utils.target_no_name", target = target)))?;
+    check_name(name)?;
+    Ok(path)
+}
+
+/// 确保目标路径的上级目录存在
+pub fn ensure_parent_dir(path: &Path) -> Result<(), ActionError> {
+    match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() && !parent.exists() => {
+            fs::create_dir_all(parent)?;
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// 根据目标路径推导 npm 包名 (只取最后一级目录名)
+pub fn package_name_from_path(path: &Path) -> String {
+    let base = path.file_name().and_then(|n| n.to_str()).unwrap_or("app");
+    suggest_package_name(base)
+}
+
+/// npm 禁止使用的包名
+const NPM_BLACKLIST: &[&str] = &["node_modules", "favicon.ico"];
+
+/// Node 内置模块名，npm 不允许作为新包名
+const NODE_BUILTINS: &[&str] = &["assert", "buffer", "child_process", "cluster", "console",
+    "constants", "crypto", "dgram", "dns", "domain", "events", "fs", "http", "http2", "https",
+    "module", "net", "os", "path", "process", "punycode", "querystring", "readline", "repl",
+    "stream", "string_decoder", "sys", "timers", "tls", "tty", "url", "util", "v8", "vm",
+    "worker_threads", "zlib"];
+
+/// 验证 npm 包名 (支持 @scope/name 形式)
+pub fn validate_package_name(name: &str) -> Result<(), ActionError> {
+    let err = |reason: String| Err(ActionError::Other(tr!("utils.package_invalid", name = name, reason = reason)));
+    if name.is_empty() {
+        return err(tr!("utils.package_empty"));
+    }
+    if name.len() > 214 {
+        return err(tr!("utils.package_too_long"));
+    }
+    if name.trim() != name {
+        return err(tr!("utils.package_spaces"));
+    }
+    if name.starts_with('.') || name.starts_with('_') {
+        return err(tr!("utils.package_leading"));
+    }
+    if name.to_lowercase() != name {
+        return err(tr!("utils.package_uppercase"));
+    }
+    if NPM_BLACKLIST.contains(&name) || NODE_BUILTINS.contains(&name) {
+        return err(tr!("utils.package_reserved"));
+    }
+    let re = Regex::new(r"^(?:@[a-z0-9\-*~][a-z0-9\-*._~]*/)?[a-z0-9\-~][a-z0-9\-._~]*$")?;
+    if !re.is_match(name) {
+        return err(tr!("utils.package_chars"));
+    }
+    Ok(())
+}
+
+/// 将任意输入转换为合法的 kebab-case 包名
+pub fn suggest_package_name(input: &str) -> String {
+    let input = input.trim();
+    match input.strip_prefix('@').and_then(|rest| rest.split_once('/')) {
+        Some((scope, name)) => {
+            let scope = kebab_segment(scope);
+            let name = kebab_segment(name);
+            match (scope.is_empty(), name.is_empty()) {
+                (false, false) => truncate_name(format!("@{}/{}", scope, name)),
+                (true, false) => finalize_name(name),
+                _ => finalize_name(scope),
+            }
+        }
+        None => finalize_name(kebab_segment(input)),
+    }
+}
+
+/// 把单段名称转换为 kebab-case，去掉非法字符
+fn kebab_segment(input: &str) -> String {
+    let mut out = String::new();
+    let mut prev_lower = false;
+    for c in input.chars() {
+        if c.is_ascii_uppercase() {
+            if prev_lower {
+                out.push('-');
+            }
+            out.push(c.to_ascii_lowercase());
+            prev_lower = false;
+        } else if c.is_ascii_lowercase() || c.is_ascii_digit() {
+            out.push(c);
+            prev_lower = true;
+        } else if c == '.' && !out.is_empty() {
+            out.push(c);
+            prev_lower = false;
+        } else if !out.ends_with('-') {
+            out.push('-');
+            prev_lower = false;
+        }
+    }
+    out.trim_matches(['-', '.']).to_string()
+}
+
+/// 兜底处理空名称和保留名称
+fn finalize_name(name: String) -> String {
+    let name = if name.is_empty() { "app".to_string() } else { name };
+    if NPM_BLACKLIST.contains(&name.as_str()) || NODE_BUILTINS.contains(&name.as_str()) {
+        truncate_name(format!("{}-app", name))
+    } else {
+        truncate_name(name)
+    }
+}
+
+/// 截断到 npm 允许的最大长度
+fn truncate_name(name: String) -> String {
+    if name.len() > 214 {
+        name[..214].trim_end_matches(['-', '.']).to_string()
+    } else {
+        name
+    }
+}
+
+/// 将 package.json 顶层的 name 字段替换为新包名，保留原有格式
+///
+/// 返回包名是否已是新包名 (没有 package.json 或顶层 name 不是字符串时为 false)
+pub fn set_package_name(dir: &Path, name: &str) -> Result<bool, ActionError> {
+    let path = dir.join("package.json");
+    let Ok(content) = fs::read_to_string(&path) else {
+        return Ok(false);
+    };
+    let value: serde_json::Value = serde_json::from_str(&content)?;
+    let Some(old) = value.get("name").and_then(|n| n.as_str()) else {
+        return Ok(false);
+    };
+    if old == name {
+        return Ok(true);
+    }
+    let Some(span) = top_level_string(&content, "name") else {
+        return Ok(false);
+    };
+    let replaced = format!("{}{}{}", &content[..span.start], serde_json::to_string(name)?, &content[span.end..]);
+    fs::write(&path, replaced)?;
+    Ok(true)
+}
+
+/// 已通过校验的 JSON 文本中，顶层对象某个键的字符串值 (含引号) 所在的字节范围
+fn top_level_string(content: &str, key: &str) -> Option<std::ops::Range<usize>> {
+    let bytes = content.as_bytes();
+    // 从开头引号起找到字符串结束后的位置，跳过转义字符
+    let string_end = |start: usize| {
+        let mut i = start + 1;
+        while i < bytes.len() {
+            match bytes[i] {
+                b'\\' => i += 2,
+                b'"' => return Some(i + 1),
+                _ => i += 1,
+            }
+        }
+        None
+    };
+    let skip_spaces = |mut i: usize| {
+        while bytes.get(i).is_some_and(u8::is_ascii_whitespace) {
+            i += 1;
+        }
+        i
+    };
+    let mut depth = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'"' => {
+                let end = string_end(i)?;
+                let colon = skip_spaces(end);
+                if depth == 1 && bytes.get(colon) == Some(&b':') {
+                    let value = skip_spaces(colon + 1);
+                    if serde_json::from_str::<String>(&content[i..end]).ok().as_deref() == Some(key) {
+                        if bytes.get(value) != Some(&b'"') {
+                            return None;
+                        }
+                        return string_end(value).map(|e| value..e);
+                    }
+                }
+                i = end;
+                continue;
+            }
+            b'{' | b'[' => depth += 1,
+            b'}' | b']' => depth -= 1,
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+/// 两个字符串的编辑距离 (按字符计算)
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut cur = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != *cb);
+            cur[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(cur[j] + 1);
+        }
+        prev = cur;
+    }
+    prev[b.len()]
+}
+
+/// 输入看起来像仓库地址而不是模板别名
+pub fn looks_like_repo(input: &str) -> bool {
+    input.contains('/') || input.contains(':') || input.ends_with(".git")
+}
+
+/// 在系统临时目录下新建只有当前用户可以访问的目录
+///
+/// 目录名包含进程号、时间和计数器；`create_dir` 遇到已存在的路径 (包括预先放置的符号链接)
+/// 会失败，此时换一个名字重试，因此不会写入他人准备好的位置。用完后由调用方删除
+pub fn private_temp_dir(prefix: &str) -> Result<PathBuf, ActionError> {
+    use std::sync::atomic::{AtomicU32, Ordering};
+    static COUNTER: AtomicU32 = AtomicU32::new(0);
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |d| d.subsec_nanos());
+    let mut builder = fs::DirBuilder::new();
+    #[cfg(unix)]
+    std::os::unix::fs::DirBuilderExt::mode(&mut builder, 0o700);
+    let mut attempts = 0;
+    loop {
+        let name = format!(
+            "{}-{}-{:x}-{}",
+            prefix,
+            std::process::id(),
+            nanos,
+            COUNTER.fetch_add(1, Ordering::SeqCst)
+        );
+        let dir = std::env::temp_dir().join(name);
+        match builder.create(&dir) {
+            Ok(()) => return Ok(dir),
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists && attempts < 100 => attempts += 1,
+            Err(e) => return Err(e.into()),
+        }
+    }
+}
+
+/// 用 `$VISUAL` / `$EDITOR` 打开文件并等待编辑器退出，都未设置时使用 vi (Windows 为 notepad)
+///
+/// 编辑器按 shell 规则拆分参数，支持 `code --wait` 这样的写法
+pub fn open_in_editor(path: &Path) -> Result<(), ActionError> {
+    let editor = ["VISUAL", "EDITOR"]
+        .iter()
+        .filter_map(|key| std::env::var(key).ok())
+        .find(|value| !value.trim().is_empty())
+        .unwrap_or_else(|| if cfg!(windows) { "notepad".into() } else { "vi".into() });
+    let words = split_command(&editor);
+    let Some((program, args)) = words.split_first() else {
+        return Err(ActionError::Other(tr!("config.editor_exit", editor = editor)));
+    };
+    let status = std::process::Command::new(program)
+        .args(args)
+        .arg(path)
+        .status()
+        .map_err(|e| ActionError::Other(tr!("config.editor_failed", editor = editor, error = e)))?;
+    if !status.success() {
+        return Err(ActionError::Other(tr!("config.editor_exit", editor = editor)));
+    }
+    Ok(())
+}
+
+/// 按 shell 规则拆分命令: 空白分隔，支持单双引号和反斜杠转义
+fn split_command(command: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut in_word = false;
+    let mut quote = None;
+    let mut chars = command.chars();
+    while let Some(c) = chars.next() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some('"'), '\\') | (None, '\\') => {
+                if let Some(next) = chars.next() {
+                    word.push(next);
+                }
+                in_word = true;
+            }
+            (Some(_), c) => word.push(c),
+            (None, '\'' | '"') => {
+                quote = Some(c);
+                in_word = true;
+            }
+            (None, c) if c.is_whitespace() => {
+                if in_word {
+                    words.push(std::mem::take(&mut word));
+                    in_word = false;
+                }
+            }
+            (None, c) => {
+                word.push(c);
+                in_word = true;
+            }
+        }
+    }
+    if in_word {
+        words.push(word);
+    }
+    words
+}
+
+/// 结构体在序列化文件中的字段名 (已应用 `rename`，不含 `skip_deserializing` 的字段)
+///
+/// 借助 serde 派生代码调用 `deserialize_struct` 时传入的字段列表取得
+pub fn struct_fields<'de, T: serde::Deserialize<'de>>() -> &'static [&'static str] {
+    use serde::de::{self, Visitor};
+
+    struct FieldNames<'a>(&'a mut &'static [&'static str]);
+
+    impl<'de> de::Deserializer<'de> for FieldNames<'_> {
+        type Error = de::value::Error;
+
+        fn deserialize_any<V: Visitor<'de>>(self, _: V) -> Result<V::Value, Self::Error> {
+            Err(de::Error::custom("not a struct"))
+        }
+
+        fn deserialize_struct<V: Visitor<'de>>(
+            self,
+            _: &'static str,
+            fields: &'static [&'static str],
+            _: V,
+        ) -> Result<V::Value, Self::Error> {
+            *self.0 = fields;
+            Err(de::Error::custom("fields collected"))
+        }
+
+        serde::forward_to_deserialize_any! {
+            bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf option unit
+            unit_struct newtype_struct seq tuple tuple_struct map enum identifier ignored_any
+        }
+    }
+
+    let mut fields: &'static [&'static str] = &[];
+    let _ = T::deserialize(FieldNames(&mut fields));
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn creates_distinct_private_temp_dirs() {
+        let a = private_temp_dir("cvue-test").unwrap();
+        let b = private_temp_dir("cvue-test").unwrap();
+        assert_ne!(a, b);
+        assert!(a.is_dir() && b.is_dir());
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            assert_eq!(fs::metadata(&a).unwrap().permissions().mode() & 0o777, 0o700);
+        }
+        let _ = fs::remove_dir_all(a);
+        let _ = fs::remove_dir_all(b);
+    }
+
+    #[test]
+    fn derives_dir_names_from_repos() {
+        let cases = [
+            ("team/admin", "admin"),
+            ("https://github.com/team/admin.git?ref=main#readme", "admin"),
+            ("git@github.com:team/admin.git", "admin"),
+            ("https://github.com/team/mono/tree/main/packages/web", "web"),
+            ("https://github.com/team/mono/-/tree/dev/apps/", "apps"),
+            ("team/tree", "tree"),
+            ("https://github.com/", "template"),
+        ];
+        for (repo, dir) in cases {
+            assert_eq!(default_dir_name(repo), dir, "{}", repo);
+        }
+    }
+
+    #[test]
+    fn resolves_targets_by_last_component() {
+        assert_eq!(resolve_target("apps/admin").unwrap(), PathBuf::from("apps/admin"));
+        assert!(resolve_target("..").is_err());
+        assert!(resolve_target("apps/").is_ok());
+        assert!(resolve_target("").is_err());
+    }
+
+    #[test]
+    fn validates_npm_package_names() {
+        let long = "a".repeat(215);
+        let valid = ["admin", "my-app.v2", "@team/admin", "@team/my_app", "a~b", &long[..214]];
+        for name in valid {
+            assert!(validate_package_name(name).is_ok(), "{}", name);
+        }
+        let invalid = [
+            "", &long, " admin", "admin ", ".admin", "_admin", "Admin", "@Team/admin", "http",
+            "node_modules", "favicon.ico", "my app", "@team/", "@/admin", "team/admin", "admin!",
+        ];
+        for name in invalid {
+            assert!(validate_package_name(name).is_err(), "{}", name);
+        }
+    }
+
+    #[test]
+    fn suggests_kebab_case_package_names() {
+        let cases = [
+            ("MyAdmin", "my-admin"),
+            ("  Vue3 Admin_Template ", "vue3-admin-template"),
+            ("@MyTeam/CoolApp", "@my-team/cool-app"),
+            ("@/CoolApp", "cool-app"),
+            ("@team/", "team"),
+            (".hidden..name.", "hidden..name"),
+            ("___", "app"),
+            ("http", "http-app"),
+            ("node_modules", "node-modules"),
+        ];
+        for (input, name) in cases {
+            assert_eq!(suggest_package_name(input), name, "{}", input);
+            assert!(validate_package_name(&suggest_package_name(input)).is_ok(), "{}", input);
+        }
+        // 截断后不以连字符结尾
+        let long = suggest_package_name(&format!("{}-{}", "a".repeat(213), "bcd"));
+        assert_eq!(long, "a".repeat(213));
+        let scoped = suggest_package_name(&format!("@team/{}", "b".repeat(220)));
+        assert_eq!(scoped.len(), 214);
+        assert!(validate_package_name(&scoped).is_ok());
+    }
+
+    #[test]
+    fn sets_only_the_top_level_package_name() {
+        let dir = private_temp_dir("cvue-utils-test").unwrap();
+        let write = |content: &str| fs::write(dir.join("package.json"), content).unwrap();
+        let read = || fs::read_to_string(dir.join("package.json")).unwrap();
+
+        assert!(!set_package_name(&dir, "app").unwrap());
+        write("{\n  \"author\": { \"name\": \"old\" },\n  \"name\" :  \"old\",\n  \"private\": true\n}\n");
+        assert!(set_package_name(&dir, "new-app").unwrap());
+        assert_eq!(read(), "{\n  \"author\": { \"name\": \"old\" },\n  \"name\" :  \"new-app\",\n  \"private\": true\n}\n");
+        // 转义写法的旧包名也能替换
+        write(r#"{"name": "my\u002dapp", "version": "1.0.0"}"#);
+        assert!(set_package_name(&dir, "admin").unwrap());
+        assert_eq!(read(), r#"{"name": "admin", "version": "1.0.0"}"#);
+        assert!(set_package_name(&dir, "admin").unwrap());
+        write(r#"{"workspaces": [{"name": "x"}]}"#);
+        assert!(!set_package_name(&dir, "admin").unwrap());
+        assert_eq!(read(), r#"{"workspaces": [{"name": "x"}]}"#);
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn splits_editor_commands() {
+        assert_eq!(split_command("vi"), ["vi"]);
+        assert_eq!(split_command("  code --wait "), ["code", "--wait"]);
+        assert_eq!(
+            split_command(r#""/Applications/Sublime Text/subl" -w 'a b' c\ d"#),
+            ["/Applications/Sublime Text/subl", "-w", "a b", "c d"]
+        );
+        assert_eq!(split_command(r#"ed "" x"#), ["ed", "", "x"]);
+        assert!(split_command("   ").is_empty());
+    }
+}